@@ -0,0 +1,70 @@
+use crate::config::{self, ConfigError, Puzzle};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use toml::value::{Table, Value};
+
+// Known answers live in `answers.toml`, in the same layout as `aoc.toml`:
+//
+//     [2022.day1]
+//     part1 = 24000
+//     part2 = 45000
+//
+//     [2022.day1.input."input/2022/day1-example.txt"]
+//     part1 = 24000
+
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+type Parts = [Option<String>; 2];
+
+#[derive(Debug, Default, Clone)]
+struct DayAnswers {
+    parts: Parts,
+    inputs: BTreeMap<PathBuf, Parts>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Answers {
+    days: BTreeMap<Puzzle, DayAnswers>,
+}
+
+fn parse_parts(table: &Table, context: &str) -> Result<Parts, String> {
+    let part = |name: &str| match table.get(name) {
+        None => Ok(None),
+        Some(Value::String(answer)) => Ok(Some(answer.clone())),
+        Some(Value::Integer(answer)) => Ok(Some(answer.to_string())),
+        Some(_) => Err(format!("{context}.{name} must be a string or an integer")),
+    };
+    Ok([part("part1")?, part("part2")?])
+}
+
+impl Answers {
+    /// Load known answers, treating a missing file as knowing none
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let days = config::load_days(path.as_ref(), |day| {
+            Ok(DayAnswers {
+                parts: parse_parts(day.table, &day.key)?,
+                inputs: day
+                    .inputs
+                    .iter()
+                    .map(|&(input, table)| {
+                        let parts = parse_parts(table, &format!("{}.input.\"{input}\"", day.key))?;
+                        Ok((PathBuf::from(input), parts))
+                    })
+                    .collect::<Result<_, String>>()?,
+            })
+        })?;
+
+        Ok(Answers {
+            days: days.into_iter().collect(),
+        })
+    }
+
+    /// The known answer to `part` of `day`, preferring one recorded for `input`
+    pub fn get(&self, year: u32, day: u32, input: Option<&Path>, part: usize) -> Option<&str> {
+        let answers = self.days.get(&(year, day))?;
+        input
+            .and_then(|input| answers.inputs.get(input))
+            .and_then(|parts| parts[part - 1].as_deref())
+            .or(answers.parts[part - 1].as_deref())
+    }
+}
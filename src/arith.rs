@@ -0,0 +1,78 @@
+use num::{CheckedAdd, CheckedMul, CheckedSub};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+// Arithmetic that can silently overflow in release builds goes through here.
+// With the `checked-arithmetic` feature every operation is checked and an
+// overflow is reported as an error; without it these are the plain operators.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub operation: char,
+    pub lhs: String,
+    pub rhs: String,
+}
+
+impl Overflow {
+    fn new(operation: char, lhs: &impl Display, rhs: &impl Display) -> Self {
+        Overflow {
+            operation,
+            lhs: lhs.to_string(),
+            rhs: rhs.to_string(),
+        }
+    }
+
+    pub fn during(self, day: u32, part: u32) -> OverflowError {
+        OverflowError {
+            day,
+            part,
+            overflow: self,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    pub day: u32,
+    pub part: u32,
+    pub overflow: Overflow,
+}
+
+impl Display for OverflowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "day {} part {}: {} {} {} overflowed",
+            self.day, self.part, self.overflow.lhs, self.overflow.operation, self.overflow.rhs
+        )
+    }
+}
+
+impl Error for OverflowError {}
+
+pub fn add<T: CheckedAdd + Display>(lhs: T, rhs: T) -> Result<T, Overflow> {
+    if cfg!(feature = "checked-arithmetic") {
+        lhs.checked_add(&rhs)
+            .ok_or_else(|| Overflow::new('+', &lhs, &rhs))
+    } else {
+        Ok(lhs + rhs)
+    }
+}
+
+pub fn sub<T: CheckedSub + Display>(lhs: T, rhs: T) -> Result<T, Overflow> {
+    if cfg!(feature = "checked-arithmetic") {
+        lhs.checked_sub(&rhs)
+            .ok_or_else(|| Overflow::new('-', &lhs, &rhs))
+    } else {
+        Ok(lhs - rhs)
+    }
+}
+
+pub fn mul<T: CheckedMul + Display>(lhs: T, rhs: T) -> Result<T, Overflow> {
+    if cfg!(feature = "checked-arithmetic") {
+        lhs.checked_mul(&rhs)
+            .ok_or_else(|| Overflow::new('*', &lhs, &rhs))
+    } else {
+        Ok(lhs * rhs)
+    }
+}
@@ -0,0 +1,95 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+// The runner can give each part a time budget. Long-running solvers poll
+// `cancelled` and stop once it is spent, answering with the best they found
+// so far marked as partial. Without a budget `cancelled` is always false.
+//
+// Each run gets its own budget, installed on the thread solving it, so a
+// solver the runner gave up on stays cancelled while the next one starts.
+
+/// The budget of one run, shared by the runner and the thread solving it
+#[derive(Debug, Clone, Default)]
+pub struct Budget {
+    deadline: Option<Instant>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Budget {
+    /// A budget running out at `deadline`
+    pub fn until(deadline: Instant) -> Self {
+        Budget {
+            deadline: Some(deadline),
+            cancelled: Arc::default(),
+        }
+    }
+
+    /// Ask the solver running on this budget to stop
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// True once the budget is spent or the solver was cancelled
+    pub fn cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+
+        let expired = matches!(self.deadline, Some(deadline) if Instant::now() >= deadline);
+        if expired {
+            self.cancel();
+        }
+        expired
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Budget> = RefCell::default();
+}
+
+/// Make `budget` the one solvers on this thread answer to
+pub fn start(budget: Budget) {
+    CURRENT.with(|current| *current.borrow_mut() = budget);
+}
+
+/// True once the budget of the solver on this thread is spent or it was cancelled
+pub fn cancelled() -> bool {
+    CURRENT.with(|current| current.borrow().cancelled())
+}
+
+/// A search that ran out of time, with the best answer it had found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cancelled {
+    pub day: u32,
+    pub part: u32,
+    pub best: String,
+}
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "day {} part {}: out of time, partial answer {}",
+            self.day, self.part, self.best
+        )
+    }
+}
+
+impl Error for Cancelled {}
+
+/// The answer of a search, or the best it found if it did not finish
+pub fn outcome<T: Display>(day: u32, part: u32, best: T, complete: bool) -> Result<T, Cancelled> {
+    if complete {
+        Ok(best)
+    } else {
+        Err(Cancelled {
+            day,
+            part,
+            best: best.to_string(),
+        })
+    }
+}
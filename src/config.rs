@@ -0,0 +1,357 @@
+use crate::runner;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use toml::value::{Table, Value};
+
+// Puzzle parameters such as the day 15 row or the day 11 round counts can be
+// overridden in `aoc.toml`, first for a whole day and then for one input:
+//
+//     [2022.day15]
+//     row = 2_000_000
+//
+//     [2022.day15.input."input/2022/day15-example.txt"]
+//     row = 10
+//     bound = 20
+//
+// Solvers look their parameters up with `param`, falling back to the puzzle's
+// own value when nothing is set. Values outside the range `LIMITS` gives a
+// parameter are reported when the config loads or the override is given.
+
+pub const DEFAULT_PATH: &str = "aoc.toml";
+
+pub type Params = BTreeMap<String, i64>;
+
+/// A puzzle by year and day
+pub type Puzzle = (u32, u32);
+
+const COUNT: RangeInclusive<i64> = 0..=i64::MAX;
+const POSITIVE: RangeInclusive<i64> = 1..=i64::MAX;
+const SCORE: RangeInclusive<i64> = 0..=u32::MAX as i64;
+
+// The values each puzzle parameter can take, so that solvers never see one they cannot use
+const LIMITS: &[(Puzzle, &str, RangeInclusive<i64>)] = &[
+    ((2022, 1), "bucket", POSITIVE),
+    ((2022, 2), "shapes", POSITIVE),
+    ((2022, 2), "rock", SCORE),
+    ((2022, 2), "paper", SCORE),
+    ((2022, 2), "scissors", SCORE),
+    ((2022, 2), "lizard", SCORE),
+    ((2022, 2), "spock", SCORE),
+    ((2022, 2), "loss", SCORE),
+    ((2022, 2), "draw", SCORE),
+    ((2022, 2), "win", SCORE),
+    ((2022, 2), "trials", POSITIVE),
+    ((2022, 2), "seed", COUNT),
+    ((2022, 3), "group_size", POSITIVE),
+    ((2022, 3), "find_groups", 0..=1),
+    ((2022, 4), "crowded", COUNT),
+    ((2022, 7), "disk_size", COUNT),
+    ((2022, 7), "space_needed", COUNT),
+    ((2022, 7), "small_dir", COUNT),
+    ((2022, 10), "crt_width", POSITIVE),
+    ((2022, 11), "part1_rounds", COUNT),
+    ((2022, 11), "part2_rounds", COUNT),
+    ((2022, 15), "bound", COUNT),
+    ((2022, 16), "minutes", 0..=u32::MAX as i64),
+    ((2022, 16), "minutes_with_elephant", 0..=u32::MAX as i64),
+];
+
+/// Why `value` is not one parameter `name` of `puzzle` can take
+pub fn check(puzzle: Puzzle, name: &str, value: i64) -> Result<(), String> {
+    let Some((_, _, range)) = LIMITS.iter().find(|(p, n, _)| *p == puzzle && *n == name) else {
+        return Ok(());
+    };
+
+    match (range.contains(&value), *range.end()) {
+        (true, _) => Ok(()),
+        (false, i64::MAX) => Err(format!(
+            "{name} = {value} must be at least {}",
+            range.start()
+        )),
+        (false, end) => Err(format!(
+            "{name} = {value} must be from {} to {end}",
+            range.start()
+        )),
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct DayConfig {
+    params: Params,
+    inputs: BTreeMap<PathBuf, Params>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Config {
+    days: BTreeMap<Puzzle, DayConfig>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, io::Error),
+    Invalid(PathBuf, String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            ConfigError::Io(path, e) => write!(f, "failed to read {}: {e}", path.display()),
+            ConfigError::Invalid(path, message) => write!(f, "{}: {message}", path.display()),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+/// A per-day TOML table and the per-input tables nested under its `input` key
+pub(crate) struct DayTable<'a> {
+    pub puzzle: Puzzle,
+    pub key: String,
+    pub table: &'a Table,
+    pub inputs: Vec<(&'a str, &'a Table)>,
+}
+
+/// Read a file made of `[YEAR.dayN]` sections, treating a missing file as empty
+pub(crate) fn load_days<T>(
+    path: &Path,
+    mut day: impl FnMut(DayTable) -> Result<T, String>,
+) -> Result<Vec<(Puzzle, T)>, ConfigError> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(ConfigError::Io(path.to_path_buf(), e)),
+    };
+    let invalid = |message| ConfigError::Invalid(path.to_path_buf(), message);
+
+    let root = toml::from_str::<Table>(&text).map_err(|e| invalid(e.to_string()))?;
+    let mut days = vec![];
+    for (year_key, value) in &root {
+        let (Ok(year), Value::Table(year_table)) = (year_key.parse::<u32>(), value) else {
+            return Err(invalid(format!("unexpected section [{year_key}]")));
+        };
+
+        for (day_key, value) in year_table {
+            let key = format!("{year}.{day_key}");
+            let (Some(number), Value::Table(table)) = (
+                day_key
+                    .strip_prefix("day")
+                    .and_then(|d| d.parse::<u32>().ok()),
+                value,
+            ) else {
+                return Err(invalid(format!("unexpected section [{key}]")));
+            };
+
+            let inputs = match table.get("input") {
+                Some(Value::Table(inputs)) => inputs
+                    .iter()
+                    .map(|(input, value)| match value {
+                        Value::Table(table) => Ok((input.as_str(), table)),
+                        _ => Err(invalid(format!("{key}.input.\"{input}\" must be a table"))),
+                    })
+                    .collect::<Result<_, _>>()?,
+                Some(_) => return Err(invalid(format!("{key}.input must be a table"))),
+                None => vec![],
+            };
+
+            let puzzle = (year, number);
+            let parsed = day(DayTable {
+                puzzle,
+                key,
+                table,
+                inputs,
+            })
+            .map_err(invalid)?;
+            days.push(((year, number), parsed));
+        }
+    }
+
+    Ok(days)
+}
+
+fn parse_params(table: &Table, puzzle: Puzzle, context: &str) -> Result<Params, String> {
+    table
+        .iter()
+        .filter(|(name, _)| name.as_str() != "input")
+        .map(|(name, value)| match value {
+            Value::Integer(n) => {
+                check(puzzle, name, *n).map_err(|e| format!("{context}.{e}"))?;
+                Ok((name.clone(), *n))
+            }
+            _ => Err(format!("{context}.{name} must be an integer")),
+        })
+        .collect()
+}
+
+impl Config {
+    /// Load a config file, treating a missing file as an empty config
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let days = load_days(path.as_ref(), |day| {
+            Ok(DayConfig {
+                params: parse_params(day.table, day.puzzle, &day.key)?,
+                inputs: day
+                    .inputs
+                    .iter()
+                    .map(|&(input, table)| {
+                        let context = format!("{}.input.\"{input}\"", day.key);
+                        let params = parse_params(table, day.puzzle, &context)?;
+                        Ok((PathBuf::from(input), params))
+                    })
+                    .collect::<Result<_, String>>()?,
+            })
+        })?;
+
+        Ok(Config {
+            days: days.into_iter().collect(),
+        })
+    }
+
+    /// Parameters for `day` of `year`, with any set for `input` taking precedence
+    pub fn params(&self, year: u32, day: u32, input: Option<&Path>) -> Params {
+        let Some(day_config) = self.days.get(&(year, day)) else {
+            return Params::new();
+        };
+
+        let mut params = day_config.params.clone();
+        if let Some(overrides) = input.and_then(|input| day_config.inputs.get(input)) {
+            params.extend(overrides.clone());
+        }
+        params
+    }
+}
+
+/// A command-line override of the form `[dayN.]name=value`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Override {
+    pub day: Option<u32>,
+    pub name: String,
+    pub value: i64,
+}
+
+impl Override {
+    pub fn parse(arg: &str) -> Option<Self> {
+        let (key, value) = arg.split_once('=')?;
+        let value = value.replace('_', "").parse().ok()?;
+        let (day, name) = match key.split_once('.') {
+            Some((day, name)) => (Some(day.strip_prefix("day")?.parse().ok()?), name),
+            None => (None, key),
+        };
+
+        Some(Override {
+            day,
+            name: name.to_string(),
+            value,
+        })
+    }
+
+    pub fn applies_to(&self, day: u32) -> bool {
+        self.day.is_none() || self.day == Some(day)
+    }
+}
+
+/// Parameters for `day` from the config file, the input and the command line, in increasing precedence
+///
+/// Fails on an override with a value the parameter cannot take.
+pub fn resolve(
+    config: &Config,
+    year: u32,
+    day: u32,
+    input: Option<&Path>,
+    overrides: &[Override],
+) -> Result<Params, String> {
+    let mut params = config.params(year, day, input);
+    for o in overrides.iter().filter(|o| o.applies_to(day)) {
+        check((year, day), &o.name, o.value).map_err(|e| format!("--set day{day}.{e}"))?;
+        params.insert(o.name.clone(), o.value);
+    }
+    Ok(params)
+}
+
+// Parameters the solvers of the year being run currently see. The runner
+// activates each day's parameters from the config it was given. Until it does,
+// as when cargo-aoc runs the solvers, the day-wide values from `aoc.toml` apply.
+static ACTIVE: RwLock<Option<BTreeMap<u32, Params>>> = RwLock::new(None);
+
+fn defaults() -> BTreeMap<u32, Params> {
+    match Config::load(DEFAULT_PATH) {
+        Ok(config) => config
+            .days
+            .iter()
+            .filter(|((year, _), _)| *year == runner::AOC_YEAR)
+            .map(|(&(_, day), day_config)| (day, day_config.params.clone()))
+            .collect(),
+        Err(e) => {
+            eprintln!("{e}, using the puzzles' own parameters");
+            BTreeMap::new()
+        }
+    }
+}
+
+/// Make `params` the ones `day` is solved with
+pub fn activate(day: u32, params: Params) {
+    ACTIVE
+        .write()
+        .unwrap()
+        .get_or_insert_with(BTreeMap::new)
+        .insert(day, params);
+}
+
+/// Look up a puzzle parameter, falling back to `default` when it is not set
+pub fn param<T>(day: u32, name: &str, default: T) -> T
+where
+    T: TryFrom<i64>,
+{
+    if ACTIVE.read().unwrap().is_none() {
+        let defaults = defaults();
+        ACTIVE.write().unwrap().get_or_insert(defaults);
+    }
+
+    let active = ACTIVE.read().unwrap();
+    match active
+        .as_ref()
+        .and_then(|a| a.get(&day))
+        .and_then(|p| p.get(name))
+    {
+        // Values were checked against `LIMITS` as they were read
+        Some(&value) => T::try_from(value).unwrap_or_else(|_| {
+            panic!("day {day} parameter {name} = {value} is missing from the limits")
+        }),
+        None => default,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn out_of_range_values_are_config_errors() {
+        let table = toml::from_str::<Table>("crt_width = 0").unwrap();
+        assert_eq!(
+            parse_params(&table, (2022, 10), "2022.day10"),
+            Err("2022.day10.crt_width = 0 must be at least 1".to_string())
+        );
+
+        let table = toml::from_str::<Table>("crt_width = 20\nrow = -3").unwrap();
+        assert!(parse_params(&table, (2022, 10), "2022.day10").is_ok());
+    }
+
+    #[test]
+    fn overrides_are_checked_for_the_days_they_apply_to() {
+        let overrides = [Override::parse("crt_width=0").unwrap()];
+        let config = Config::default();
+        assert_eq!(
+            resolve(&config, 2022, 10, None, &overrides),
+            Err("--set day10.crt_width = 0 must be at least 1".to_string())
+        );
+        assert!(resolve(&config, 2022, 15, None, &overrides).is_ok());
+
+        let overrides = [Override::parse("day2.rock=-1").unwrap()];
+        assert!(resolve(&config, 2022, 2, None, &overrides).is_err());
+    }
+}
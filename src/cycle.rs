@@ -0,0 +1,142 @@
+use num::PrimInt;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    // Steps taken before the first state of the cycle is reached
+    pub prefix: usize,
+    // Steps taken to return to the same state
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The step within the first `prefix + period` steps whose state matches step `n`
+    pub fn position(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+
+    /// Extrapolate a running metric to step `n`
+    ///
+    /// `history[i]` is the metric after `i` steps, and must cover at least
+    /// `prefix + period + 1` steps. The metric is assumed to change by the
+    /// same amount every time the cycle repeats.
+    pub fn extrapolate<T: PrimInt>(&self, history: &[T], n: usize) -> T {
+        if n < history.len() {
+            return history[n];
+        }
+
+        let start = history[self.prefix];
+        let per_cycle = history[self.prefix + self.period] - start;
+        let cycles = T::from((n - self.prefix) / self.period).expect("too many cycles");
+
+        history[self.position(n)] + cycles * per_cycle
+    }
+}
+
+/// Floyd's tortoise and hare, giving up after `limit` steps of the hare
+pub fn floyd<S: PartialEq>(start: S, step: impl Fn(&S) -> S, limit: usize) -> Option<Cycle> {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    let mut taken = 2;
+
+    while tortoise != hare {
+        if taken >= limit {
+            return None;
+        }
+        tortoise = step(&tortoise);
+        hare = step(&step(&hare));
+        taken += 2;
+    }
+
+    // Meeting point is a multiple of the period away from the start
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Some(Cycle { prefix, period })
+}
+
+/// Brent's algorithm, giving up after `limit` steps
+pub fn brent<S: Clone + PartialEq>(
+    start: S,
+    step: impl Fn(&S) -> S,
+    limit: usize,
+) -> Option<Cycle> {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    let mut taken = 1;
+
+    while tortoise != hare {
+        if taken >= limit {
+            return None;
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+        taken += 1;
+    }
+
+    // Start a hare one period ahead and walk both until they meet
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Some(Cycle { prefix, period })
+}
+
+/// Remember every state until one repeats or `limit` steps have been taken
+///
+/// Returns every distinct state in the order they were reached, along with
+/// the cycle if one was found. `step` is called exactly once per state, in order.
+pub fn hashed<S: Clone + Eq + Hash>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    limit: usize,
+) -> (Vec<S>, Option<Cycle>) {
+    let mut seen = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+
+    while states.len() <= limit {
+        let next = step(states.last().unwrap());
+
+        if let Some(&prefix) = seen.get(&next) {
+            let period = states.len() - prefix;
+            return (states, Some(Cycle { prefix, period }));
+        }
+
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+
+    (states, None)
+}
@@ -0,0 +1,177 @@
+use crate::simulation::Simulation;
+use crate::year2022::{day10, day11, day14, day20, day5, day9};
+use std::io::{self, BufRead, Write};
+
+pub const YEAR: u32 = 2022;
+pub const DAYS: [u32; 6] = [5, 9, 10, 11, 14, 20];
+
+const HELP: &str = "commands:
+  s, <enter>    single step
+  s <n>         take <n> steps
+  r <n>         run to step <n>
+  u <text>      run until the state contains <text>
+  c             run to the end
+  p             print the current state
+  h             show this help
+  q             quit";
+
+fn advance<S: Simulation>(sim: &mut S, steps: &mut usize, mut stop: impl FnMut(&S, usize) -> bool) {
+    while !sim.is_done() {
+        sim.step();
+        *steps += 1;
+
+        if stop(sim, *steps) {
+            break;
+        }
+    }
+}
+
+fn print_state<S: Simulation>(sim: &S, steps: usize, out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "── step {steps} ──")?;
+    writeln!(out, "{}", sim.snapshot())?;
+    if sim.is_done() {
+        writeln!(out, "── finished after {steps} steps ──")?;
+    }
+    Ok(())
+}
+
+/// Interactively step through a simulation, reading commands from `input`
+pub fn debug<S: Simulation>(
+    mut sim: S,
+    input: impl BufRead,
+    mut out: impl Write,
+) -> io::Result<()> {
+    let mut steps = 0;
+
+    print_state(&sim, steps, &mut out)?;
+    writeln!(out, "{HELP}")?;
+
+    let mut lines = input.lines();
+    loop {
+        write!(out, "step {steps}> ")?;
+        out.flush()?;
+
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let line = line?;
+        let (command, argument) = line
+            .trim()
+            .split_once(' ')
+            .map(|(c, a)| (c, a.trim()))
+            .unwrap_or((line.trim(), ""));
+
+        match command {
+            "" | "s" => {
+                let Ok(count) = (if argument.is_empty() {
+                    Ok(1)
+                } else {
+                    argument.parse::<usize>()
+                }) else {
+                    writeln!(out, "not a number: {argument}")?;
+                    continue;
+                };
+                let target = steps + count;
+                advance(&mut sim, &mut steps, |_, n| n >= target);
+            }
+            "r" => {
+                let Ok(target) = argument.parse::<usize>() else {
+                    writeln!(out, "not a number: {argument}")?;
+                    continue;
+                };
+                if target <= steps {
+                    writeln!(out, "already past step {target}")?;
+                    continue;
+                }
+                advance(&mut sim, &mut steps, |_, n| n >= target);
+            }
+            "u" if !argument.is_empty() => {
+                advance(&mut sim, &mut steps, |s, _| {
+                    s.snapshot().to_string().contains(argument)
+                });
+            }
+            "c" => advance(&mut sim, &mut steps, |_, _| false),
+            "p" => {}
+            "q" => break,
+            _ => {
+                writeln!(out, "{HELP}")?;
+                continue;
+            }
+        }
+
+        print_state(&sim, steps, &mut out)?;
+    }
+
+    Ok(())
+}
+
+/// Step through `part` of `day` on the given puzzle input
+pub fn debug_day(
+    day: u32,
+    part: u32,
+    puzzle: &str,
+    input: impl BufRead,
+    out: impl Write,
+) -> io::Result<()> {
+    let part2 = match part {
+        1 => false,
+        2 => true,
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("no part {part}"),
+            ))
+        }
+    };
+
+    match day {
+        5 => {
+            let puzzle = day5::day5_generator(puzzle);
+            let model = if part2 {
+                day5::CrateMover::Model9001
+            } else {
+                day5::CrateMover::Model9000
+            };
+            debug(day5::Crane::new(&puzzle, model), input, out)
+        }
+        9 => {
+            let puzzle = day9::day9_generator(puzzle);
+            debug(
+                day9::Rope::new(&puzzle, if part2 { 10 } else { 2 }),
+                input,
+                out,
+            )
+        }
+        10 => {
+            let puzzle = day10::day10_generator(puzzle);
+            debug(day10::Cpu::new(&puzzle), input, out)
+        }
+        11 => {
+            let puzzle = day11::day11_generator(puzzle);
+            let sim = if part2 {
+                day11::KeepAway::new(&puzzle, day11::Relief::Bounded, day11::rounds(2))
+            } else {
+                day11::KeepAway::new(&puzzle, day11::Relief::Divide(3), day11::rounds(1))
+            };
+            debug(sim, input, out)
+        }
+        14 => {
+            let puzzle = day14::day14_generator(puzzle);
+            debug(day14::SandCave::new(&puzzle, part2), input, out)
+        }
+        20 => {
+            let puzzle = day20::day20_generator(puzzle);
+            let sim = if part2 {
+                day20::Mixer::new(&puzzle, day20::decryption_key(), 10)
+            } else {
+                day20::Mixer::new(&puzzle, 1, 1)
+            }
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.during(20, part)))?;
+            debug(sim, input, out)
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("day {day} has no step-through simulation"),
+        )),
+    }
+}
@@ -0,0 +1,95 @@
+use crate::year2022::{day11, day16, day7};
+use std::fmt::{Display, Formatter};
+
+pub const YEAR: u32 = 2022;
+
+/// Graphs each day can export, the first being the default
+pub const GRAPHS: &[(u32, &[&str])] = &[
+    (7, &["tree"]),
+    (11, &["throws"]),
+    (16, &["tunnels", "compressed"]),
+];
+
+/// Attributes for nodes and edges on the highlighted route
+pub const HIGHLIGHT: &[(&str, &str)] = &[("color", "red"), ("penwidth", "2.5")];
+
+// Line breaks become DOT's centred `\n`
+fn quote(text: &str) -> String {
+    let text = text
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{text}\"")
+}
+
+fn attributes(list: &[(&str, &str)]) -> String {
+    if list.is_empty() {
+        return String::new();
+    }
+
+    let list = list
+        .iter()
+        .map(|(name, value)| format!("{name}={}", quote(value)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(" [{list}]")
+}
+
+/// A Graphviz graph, written out in the DOT language
+pub struct Graph {
+    name: String,
+    directed: bool,
+    statements: Vec<String>,
+}
+
+impl Graph {
+    pub fn new(name: &str, directed: bool) -> Self {
+        Graph {
+            name: name.to_string(),
+            directed,
+            statements: vec![],
+        }
+    }
+
+    /// An attribute applying to the whole graph, such as `rankdir`
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.statements.push(format!("{name}={}", quote(value)));
+    }
+
+    pub fn node(&mut self, id: &str, attrs: &[(&str, &str)]) {
+        self.statements
+            .push(format!("{}{}", quote(id), attributes(attrs)));
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, attrs: &[(&str, &str)]) {
+        let arrow = if self.directed { "->" } else { "--" };
+        self.statements.push(format!(
+            "{} {arrow} {}{}",
+            quote(from),
+            quote(to),
+            attributes(attrs)
+        ));
+    }
+}
+
+impl Display for Graph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let kind = if self.directed { "digraph" } else { "graph" };
+        writeln!(f, "{kind} {} {{", quote(&self.name))?;
+        for statement in &self.statements {
+            writeln!(f, "    {statement};")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// The DOT source of `graph` for `day` on the given puzzle input
+pub fn export(day: u32, graph: &str, puzzle: &str) -> Option<Graph> {
+    match (day, graph) {
+        (7, "tree") => Some(day7::directory_graph(&day7::day7_generator(puzzle))),
+        (11, "throws") => Some(day11::throw_graph(&day11::day11_generator(puzzle))),
+        (16, "tunnels") => Some(day16::tunnel_graph(&day16::day16_generator(puzzle), false)),
+        (16, "compressed") => Some(day16::tunnel_graph(&day16::day16_generator(puzzle), true)),
+        _ => None,
+    }
+}
@@ -0,0 +1,41 @@
+use crate::budget;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+// Solvers can give a step-by-step account of how they reach an answer, shown
+// by `run --explain`. Steps are only formatted while explanations are on.
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static STEPS: Mutex<Vec<String>> = Mutex::new(vec![]);
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn record(step: String) {
+    // A solver the runner gave up on must not add to the next one's account
+    if budget::cancelled() {
+        return;
+    }
+    STEPS.lock().unwrap().push(step);
+}
+
+/// The steps recorded since the last call
+pub fn take() -> Vec<String> {
+    std::mem::take(&mut *STEPS.lock().unwrap())
+}
+
+/// Record a step of the explanation, formatted like `format!`
+macro_rules! step {
+    ($($arg:tt)*) => {
+        if $crate::explain::enabled() {
+            $crate::explain::record(format!($($arg)*));
+        }
+    };
+}
+
+pub(crate) use step;
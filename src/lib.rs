@@ -1,23 +1,24 @@
-#![feature(let_chains)]
-#![feature(stmt_expr_attributes)]
 use aoc_runner_derive::aoc_lib;
 
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day2;
-mod day20;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
+pub mod allocations;
+pub mod answers;
+pub mod arith;
+pub mod budget;
+pub mod config;
+pub mod cycle;
+pub mod debugger;
+pub mod dot;
+pub mod explain;
+pub mod optimize;
+pub mod parse;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod simulation;
+pub mod watch;
 
+pub mod year2022;
+
+// cargo-aoc only knows about one year, see `runner::AOC_YEAR`
 aoc_lib! { year = 2022 }
@@ -0,0 +1,520 @@
+use advent_of_code_2022::allocations;
+use advent_of_code_2022::answers::{self, Answers};
+use advent_of_code_2022::budget::{self, Budget};
+use advent_of_code_2022::config::{self, Config, Override};
+use advent_of_code_2022::report::{self, DayReport};
+use advent_of_code_2022::runner::{self, Day, Solution};
+use advent_of_code_2022::watch::{self, Watcher};
+use advent_of_code_2022::{debugger, dot, explain, scaffold};
+use colored::Colorize;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+use std::{env, fs, io, panic, process, thread};
+
+const USAGE: &str = "usage:
+  advent-of-code-2022 run <day|all> [options]
+  advent-of-code-2022 watch <day> [options]
+  advent-of-code-2022 report [day|all] [--output <path>] [options]
+  advent-of-code-2022 new <day> [--year <year>]
+  advent-of-code-2022 debug <day> [part] [options]
+  advent-of-code-2022 dot <day> [graph] [--output <path>] [options]
+
+options:
+  --year <year>                 puzzle year, the latest solved one by default
+  --input <path>                input file instead of input/<year>/day<day>.txt
+  --config <path>               parameter file instead of aoc.toml
+  --set [dayN.]name=value       override a puzzle parameter
+  --budget <seconds>            give up on a part after this long
+  --explain                     show how each answer was reached (run only)";
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    process::exit(2)
+}
+
+fn input_path(year: u32, day: u32, path: &Option<String>) -> String {
+    path.clone()
+        .unwrap_or_else(|| format!("input/{year}/day{day}.txt"))
+}
+
+fn read_input(path: &str) -> String {
+    match fs::read_to_string(path) {
+        Ok(input) => input.trim_end_matches('\n').to_string(),
+        Err(e) => {
+            eprintln!("failed to read {path}: {e}");
+            process::exit(1)
+        }
+    }
+}
+
+/// Options shared by every command
+struct Options {
+    positional: Vec<String>,
+    year: u32,
+    input: Option<String>,
+    config: Option<String>,
+    overrides: Vec<Override>,
+    output: Option<String>,
+    budget: Option<Duration>,
+    explain: bool,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Self {
+        let mut options = Options {
+            positional: vec![],
+            year: runner::latest_year(),
+            input: None,
+            config: None,
+            overrides: vec![],
+            output: None,
+            budget: None,
+            explain: false,
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().cloned().unwrap_or_else(|| usage());
+            match arg.as_str() {
+                "--year" => options.year = value().parse().unwrap_or_else(|_| usage()),
+                "--input" => options.input = Some(value()),
+                "--config" => options.config = Some(value()),
+                "--output" => options.output = Some(value()),
+                "--explain" => options.explain = true,
+                "--budget" => {
+                    let seconds = value().parse::<f64>().unwrap_or_else(|_| usage());
+                    options.budget = Some(Duration::from_secs_f64(seconds));
+                }
+                "--set" => {
+                    let value = value();
+                    let o = Override::parse(&value).unwrap_or_else(|| {
+                        eprintln!("invalid override {value}, expected [dayN.]name=value");
+                        process::exit(2)
+                    });
+                    options.overrides.push(o);
+                }
+                _ => options.positional.push(arg.clone()),
+            }
+        }
+
+        options
+    }
+
+    fn config_path(&self) -> &str {
+        self.config.as_deref().unwrap_or(config::DEFAULT_PATH)
+    }
+
+    fn config(&self) -> Config {
+        Config::load(self.config_path()).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1)
+        })
+    }
+
+    /// Read the input for `day` and make its parameters active
+    fn prepare(&self, config: &Config, day: u32) -> String {
+        let path = input_path(self.year, day, &self.input);
+        let params = config::resolve(
+            config,
+            self.year,
+            day,
+            Some(Path::new(&path)),
+            &self.overrides,
+        )
+        .unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1)
+        });
+        config::activate(day, params);
+        read_input(&path)
+    }
+
+    /// The days named by the positional `<day|all>` argument
+    fn days(&self) -> Vec<&'static Day> {
+        let Some(solved) = runner::days(self.year) else {
+            eprintln!("no solutions for {}", self.year);
+            process::exit(1)
+        };
+        let days = match self
+            .positional
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()[..]
+        {
+            ["all"] => solved.iter().collect(),
+            [day] => match solved.iter().find(|d| day.parse() == Ok(d.day)) {
+                Some(day) => vec![day],
+                None => {
+                    eprintln!("no solution for day {day} of {}", self.year);
+                    process::exit(1)
+                }
+            },
+            _ => usage(),
+        };
+        if days.len() > 1 && self.input.is_some() {
+            eprintln!("--input needs a single day");
+            process::exit(2)
+        }
+        days
+    }
+}
+
+// How long past its budget a solver gets to hand in a partial answer
+const GRACE: Duration = Duration::from_millis(500);
+
+/// Solve a part and time it, giving up on it once `budget` is spent
+///
+/// With a budget the solver runs on its own thread, so one that never checks
+/// for cancellation is left behind rather than holding up the days after it.
+fn timed<T: Send + 'static>(
+    solve: impl FnOnce(&str) -> Result<T, Box<dyn Error + Send + Sync>> + Send + 'static,
+    puzzle: &str,
+    budget: Option<Duration>,
+) -> (Result<T, Box<dyn Error + Send + Sync>>, Duration) {
+    let start = Instant::now();
+    let Some(budget) = budget else {
+        let answer = solve(puzzle);
+        return (answer, start.elapsed());
+    };
+
+    let run = Budget::until(start + budget);
+    let (sender, receiver) = mpsc::channel();
+    let puzzle = puzzle.to_string();
+    let token = run.clone();
+    thread::spawn(move || {
+        budget::start(token);
+        sender.send(solve(&puzzle))
+    });
+
+    let answer = match receiver.recv_timeout(budget + GRACE) {
+        Ok(answer) => answer,
+        Err(RecvTimeoutError::Timeout) => {
+            // Keep telling the abandoned solver to stop, whatever runs next
+            run.cancel();
+            Err(format!("out of time after {budget:.2?}, abandoned").into())
+        }
+        Err(RecvTimeoutError::Disconnected) => Err("solver panicked".into()),
+    };
+    let elapsed = start.elapsed();
+    (answer, elapsed)
+}
+
+/// Solve a part, turning a panicking solver into an error
+fn solve_catching(solve: fn(&str) -> Solution, puzzle: &str) -> Solution {
+    panic::catch_unwind(|| solve(puzzle)).unwrap_or_else(|payload| {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "solver panicked".to_string(),
+            },
+        };
+        Err(message.into())
+    })
+}
+
+fn run(args: &[String]) {
+    let options = Options::parse(args);
+    let days = options.days();
+
+    if options.explain {
+        explain::enable();
+    }
+
+    let config = options.config();
+    for day in days {
+        let puzzle = options.prepare(&config, day.day);
+        println!("{} day {}", options.year, day.day);
+        for (part, &solve) in day.parts.iter().enumerate() {
+            explain::take();
+            let (answer, elapsed) = timed(solve, &puzzle, options.budget);
+            match answer {
+                Ok(answer) => println!("  part {}: {answer} ({elapsed:.2?})", part + 1),
+                Err(e) => println!("  part {}: error: {e} ({elapsed:.2?})", part + 1),
+            }
+            for step in explain::take() {
+                println!("    {step}");
+            }
+        }
+
+        if allocations::ENABLED {
+            // Solves the day once more, so it gets the same budget as the parts
+            let measure = day.allocations;
+            match timed(move |puzzle| Ok(measure(puzzle)), &puzzle, options.budget).0 {
+                Ok([generator, part1, part2]) => {
+                    println!("  allocations in generator, all threads: {generator}");
+                    println!("  allocations in part 1, all threads: {part1}");
+                    println!("  allocations in part 2, all threads: {part2}");
+                }
+                Err(e) => println!("  allocations: error: {e}"),
+            }
+        }
+    }
+}
+
+/// Print an answer alongside the previous and known ones, highlighting what changed
+fn show_answer(part: usize, answer: Option<&str>, previous: Option<&str>, known: Option<&str>) {
+    let Some(answer) = answer else {
+        println!("  part {part}: {}", "no answer".red());
+        return;
+    };
+
+    let changed = matches!(previous, Some(previous) if previous != answer);
+    let shown = match known {
+        Some(known) if known == answer => answer.green(),
+        Some(_) => answer.red(),
+        None if changed => answer.yellow(),
+        None => answer.normal(),
+    };
+    let shown = if changed { shown.bold() } else { shown };
+    print!("  part {part}: {shown}");
+
+    match previous {
+        Some(previous) if previous != answer => print!("  {} {previous}", "was".dimmed()),
+        Some(_) => print!("  {}", "unchanged".dimmed()),
+        None => {}
+    }
+    match known {
+        Some(known) if known == answer => print!("  {}", "correct".green()),
+        Some(known) => print!("  {} {known}", "expected".red()),
+        None => {}
+    }
+    println!();
+}
+
+fn watch(args: &[String]) {
+    let options = Options::parse(args);
+    let day = match options.positional[..] {
+        [ref day] => day.parse::<u32>().unwrap_or_else(|_| usage()),
+        _ => usage(),
+    };
+    let year = options.year;
+    let input = input_path(year, day, &options.input);
+
+    let mut watcher = Watcher::new(
+        vec![
+            PathBuf::from(format!("src/year{year}/day{day}.rs")),
+            PathBuf::from(&input),
+            PathBuf::from(options.config_path()),
+        ],
+        Duration::from_millis(500),
+    );
+
+    // Solve through cargo so changes to the solver are rebuilt
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command.args(["run", "--quiet"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command.args(["--", "run"]).args(args);
+
+    let mut previous: [Option<String>; 2] = [None, None];
+    let mut reason = "started".to_string();
+    loop {
+        println!("{}", format!("== {year} day {day} ({reason})").bold());
+
+        match command.output() {
+            Ok(output) if output.status.success() => {
+                let current = watch::parse_answers(&String::from_utf8_lossy(&output.stdout));
+                let known = load_answers();
+                for part in 1..=2 {
+                    show_answer(
+                        part,
+                        current[part - 1].as_deref(),
+                        previous[part - 1].as_deref(),
+                        known.get(year, day, Some(Path::new(&input)), part),
+                    );
+                }
+                previous = current;
+            }
+            Ok(output) => {
+                eprint!("{}", String::from_utf8_lossy(&output.stderr));
+                println!("{}", "failed, waiting for changes".red());
+            }
+            Err(e) => {
+                eprintln!("failed to run cargo: {e}");
+                process::exit(1)
+            }
+        }
+
+        let changed = watcher.wait();
+        reason = changed
+            .iter()
+            .map(|path| format!("{} changed", path.display()))
+            .collect::<Vec<_>>()
+            .join(", ");
+    }
+}
+
+fn load_answers() -> Answers {
+    Answers::load(answers::DEFAULT_PATH).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        Answers::default()
+    })
+}
+
+fn report(args: &[String]) {
+    let mut options = Options::parse(args);
+    if options.positional.is_empty() {
+        options.positional.push("all".to_string());
+    }
+    let days = options.days();
+    let output = options.output.as_deref().unwrap_or("report.html");
+
+    let config = options.config();
+    let known = load_answers();
+    let mut reports = vec![];
+    for day in days {
+        let path = input_path(options.year, day.day, &options.input);
+        let puzzle = options.prepare(&config, day.day);
+        eprintln!("solving {} day {}", options.year, day.day);
+
+        let parts = day
+            .parts
+            .iter()
+            .enumerate()
+            .map(|(part, &solve)| {
+                let (answer, elapsed) =
+                    timed(move |p| solve_catching(solve, p), &puzzle, options.budget);
+                report::Part {
+                    answer: answer.map_err(|e| e.to_string()),
+                    elapsed,
+                    known: known
+                        .get(options.year, day.day, Some(Path::new(&path)), part + 1)
+                        .map(str::to_string),
+                }
+            })
+            .collect();
+        // A picture of a failing day is not worth losing the rest of the report over
+        let picture = if options.year == report::YEAR {
+            panic::catch_unwind(|| report::picture(day.day, &puzzle)).unwrap_or(None)
+        } else {
+            None
+        };
+
+        reports.push(DayReport {
+            day: day.day,
+            parts,
+            picture,
+        });
+    }
+
+    if let Err(e) = fs::write(output, report::render(options.year, &reports)) {
+        eprintln!("failed to write {output}: {e}");
+        process::exit(1)
+    }
+    println!("wrote {output}");
+}
+
+fn new(args: &[String]) {
+    let options = Options::parse(args);
+    let day = match options.positional[..] {
+        [ref day] => day.parse::<u32>().unwrap_or_else(|_| usage()),
+        _ => usage(),
+    };
+
+    match scaffold::new_day(Path::new("."), options.year, day) {
+        Ok(touched) => {
+            for path in touched {
+                println!("wrote {}", path.display());
+            }
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1)
+        }
+    }
+}
+
+fn debug(args: &[String]) {
+    let options = Options::parse(args);
+    let positional = options
+        .positional
+        .iter()
+        .map(|arg| arg.parse::<u32>().unwrap_or_else(|_| usage()))
+        .collect::<Vec<_>>();
+
+    let (day, part) = match positional[..] {
+        [day] => (day, 1),
+        [day, part] => (day, part),
+        _ => usage(),
+    };
+
+    if options.year != debugger::YEAR || !debugger::DAYS.contains(&day) {
+        eprintln!(
+            "{} day {day} has no step-through simulation (available: {} days {:?})",
+            options.year,
+            debugger::YEAR,
+            debugger::DAYS
+        );
+        process::exit(1)
+    }
+
+    let puzzle = options.prepare(&options.config(), day);
+    if let Err(e) = debugger::debug_day(day, part, &puzzle, io::stdin().lock(), io::stdout()) {
+        eprintln!("{e}");
+        process::exit(1)
+    }
+}
+
+fn graph(args: &[String]) {
+    let options = Options::parse(args);
+    let (day, name) = match &options.positional[..] {
+        [day] => (day, None),
+        [day, name] => (day, Some(name.as_str())),
+        _ => usage(),
+    };
+    let day = day.parse::<u32>().unwrap_or_else(|_| usage());
+
+    let graphs = dot::GRAPHS
+        .iter()
+        .find(|&&(d, _)| d == day)
+        .map_or(&[][..], |&(_, graphs)| graphs);
+    let name = match name.or(graphs.first().copied()) {
+        Some(name) if options.year == dot::YEAR && graphs.contains(&name) => name,
+        _ => {
+            let available = dot::GRAPHS
+                .iter()
+                .map(|(day, graphs)| format!("day {day}: {}", graphs.join(", ")))
+                .collect::<Vec<_>>()
+                .join("; ");
+            eprintln!(
+                "{} day {day} has no such graph (available in {}: {available})",
+                options.year,
+                dot::YEAR
+            );
+            process::exit(1)
+        }
+    };
+
+    let puzzle = options.prepare(&options.config(), day);
+    let graph = dot::export(day, name, &puzzle).unwrap().to_string();
+    match &options.output {
+        Some(output) => {
+            if let Err(e) = fs::write(output, graph) {
+                eprintln!("failed to write {output}: {e}");
+                process::exit(1)
+            }
+            println!("wrote {output}");
+        }
+        None => print!("{graph}"),
+    }
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("watch") => watch(&args[1..]),
+        Some("report") => report(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("debug") => debug(&args[1..]),
+        Some("dot") => graph(&args[1..]),
+        _ => usage(),
+    }
+}
@@ -0,0 +1,204 @@
+use crate::budget;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+/// A maximization problem explored as a tree of partial solutions
+///
+/// Every state is a valid solution in its own right, worth `value`.
+pub trait Problem {
+    type State: Clone;
+    type Value: Copy + Ord;
+    type Key: Hash + Eq;
+
+    fn value(&self, state: &Self::State) -> Self::Value;
+
+    /// Optimistic estimate of the best value reachable from `state`
+    ///
+    /// Must never underestimate, or the optimum may be pruned.
+    fn upper_bound(&self, state: &Self::State) -> Self::Value;
+
+    fn branches(&self, state: &Self::State) -> Vec<Self::State>;
+
+    /// States sharing a key are checked against each other for dominance
+    fn key(&self, _state: &Self::State) -> Option<Self::Key> {
+        None
+    }
+
+    /// True if `state` can always do at least as well as `other`
+    fn dominates(&self, _state: &Self::State, _other: &Self::State) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub expanded: usize,
+    pub pruned_by_bound: usize,
+    pub pruned_by_dominance: usize,
+}
+
+impl Stats {
+    pub fn pruned(&self) -> usize {
+        self.pruned_by_bound + self.pruned_by_dominance
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "expanded {} nodes, pruned {} ({} by bound, {} by dominance)",
+            self.expanded,
+            self.pruned(),
+            self.pruned_by_bound,
+            self.pruned_by_dominance
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Best<S, V> {
+    pub state: S,
+    pub value: V,
+    pub stats: Stats,
+    /// False if the search ran out of time before proving `value` optimal
+    pub complete: bool,
+}
+
+struct Search<'a, P: Problem> {
+    problem: &'a P,
+    best: P::State,
+    best_value: P::Value,
+    seen: HashMap<P::Key, Vec<P::State>>,
+    stats: Stats,
+    complete: bool,
+}
+
+impl<'a, P: Problem> Search<'a, P> {
+    fn new(problem: &'a P, start: &P::State) -> Self {
+        Search {
+            problem,
+            best: start.clone(),
+            best_value: problem.value(start),
+            seen: HashMap::new(),
+            stats: Stats::default(),
+            complete: true,
+        }
+    }
+
+    // Record `state` as a candidate, returning false if it should not be expanded
+    fn admit(&mut self, state: &P::State) -> bool {
+        let value = self.problem.value(state);
+        if value > self.best_value {
+            self.best = state.clone();
+            self.best_value = value;
+        }
+
+        if self.problem.upper_bound(state) <= self.best_value {
+            self.stats.pruned_by_bound += 1;
+            return false;
+        }
+
+        if let Some(key) = self.problem.key(state) {
+            let seen = self.seen.entry(key).or_default();
+            if seen
+                .iter()
+                .any(|other| self.problem.dominates(other, state))
+            {
+                self.stats.pruned_by_dominance += 1;
+                return false;
+            }
+            seen.retain(|other| !self.problem.dominates(state, other));
+            seen.push(state.clone());
+        }
+
+        true
+    }
+
+    fn finish(self) -> Best<P::State, P::Value> {
+        Best {
+            state: self.best,
+            value: self.best_value,
+            stats: self.stats,
+            complete: self.complete,
+        }
+    }
+}
+
+/// Depth-first branch and bound
+pub fn depth_first<P: Problem>(problem: &P, start: P::State) -> Best<P::State, P::Value> {
+    let mut search = Search::new(problem, &start);
+    let mut stack = vec![start];
+
+    while let Some(state) = stack.pop() {
+        if budget::cancelled() {
+            search.complete = false;
+            break;
+        }
+
+        // The best value may have improved since this state was pushed
+        if problem.upper_bound(&state) <= search.best_value {
+            search.stats.pruned_by_bound += 1;
+            continue;
+        }
+
+        search.stats.expanded += 1;
+        for next in problem.branches(&state) {
+            if search.admit(&next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    search.finish()
+}
+
+struct Candidate<S, V>(V, S);
+
+impl<S, V: PartialEq> PartialEq for Candidate<S, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<S, V: Eq> Eq for Candidate<S, V> {}
+
+impl<S, V: Ord> PartialOrd for Candidate<S, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, V: Ord> Ord for Candidate<S, V> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+/// Best-first branch and bound, always expanding the state with the highest upper bound
+pub fn best_first<P: Problem>(problem: &P, start: P::State) -> Best<P::State, P::Value> {
+    let mut search = Search::new(problem, &start);
+    let mut queue = BinaryHeap::from([Candidate(problem.upper_bound(&start), start)]);
+
+    while let Some(Candidate(bound, state)) = queue.pop() {
+        if bound <= search.best_value {
+            // Nothing left in the queue can do better
+            search.stats.pruned_by_bound += 1 + queue.len();
+            break;
+        }
+        if budget::cancelled() {
+            search.complete = false;
+            break;
+        }
+
+        search.stats.expanded += 1;
+        for next in problem.branches(&state) {
+            if search.admit(&next) {
+                queue.push(Candidate(problem.upper_bound(&next), next));
+            }
+        }
+    }
+
+    search.finish()
+}
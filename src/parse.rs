@@ -0,0 +1,27 @@
+use nom::{
+    bytes::complete::tag, character::complete::line_ending, error::Error, multi::separated_list1,
+    IResult, Parser,
+};
+
+/// One or more lines, each parsed with `line`
+pub fn lines<'a, O>(
+    line: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(line_ending, line)
+}
+
+/// One or more blocks separated by blank lines, each parsed with `block`
+pub fn blocks<'a, O>(
+    block: impl Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(tag("\n\n"), block)
+}
+
+/// Run `parser` over the whole of `input`, panicking if any of it is left over
+pub fn all<'a, O>(mut parser: impl Parser<&'a str, O, Error<&'a str>>, input: &'a str) -> O {
+    let (rest, output) = parser
+        .parse(input)
+        .unwrap_or_else(|e| panic!("failed to parse input: {e}"));
+    assert!(rest.is_empty(), "unparsed input: {rest:?}");
+    output
+}
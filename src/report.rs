@@ -0,0 +1,384 @@
+use crate::simulation::Simulation;
+use crate::year2022::day2::{self, Reading, StrategyError};
+use crate::year2022::{day10, day12, day14, day8};
+use std::fmt::Write;
+use std::time::Duration;
+
+pub const YEAR: u32 = 2022;
+
+// Largest side of a rendered grid in pixels
+const PICTURE_SIZE: usize = 600;
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em auto; max-width: 60em; color: #222; }
+table { border-collapse: collapse; }
+th, td { padding: 0.3em 0.8em; border-bottom: 1px solid #ddd; text-align: left; vertical-align: top; }
+pre { margin: 0; }
+.correct { color: #1a7f37; }
+.wrong, .error { color: #cf222e; }
+.unchecked { color: #888; }
+svg { display: block; margin: 1em 0; }
+";
+
+/// How one part of a day went
+pub struct Part {
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+    pub known: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Wrong,
+    Error,
+    Unchecked,
+}
+
+impl Part {
+    pub fn status(&self) -> Status {
+        match (&self.answer, &self.known) {
+            (Err(_), _) => Status::Error,
+            (Ok(answer), Some(known)) if answer == known => Status::Correct,
+            (Ok(_), Some(_)) => Status::Wrong,
+            (Ok(_), None) => Status::Unchecked,
+        }
+    }
+}
+
+impl Status {
+    fn class(self) -> &'static str {
+        match self {
+            Status::Correct => "correct",
+            Status::Wrong => "wrong",
+            Status::Error => "error",
+            Status::Unchecked => "unchecked",
+        }
+    }
+}
+
+/// Everything the report shows about one day
+pub struct DayReport {
+    pub day: u32,
+    pub parts: Vec<Part>,
+    pub picture: Option<String>,
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn svg(width: usize, height: usize, body: &str) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">\n{body}</svg>\n"
+    )
+}
+
+/// Pixels per cell for a grid of `columns` by `rows`
+fn cell_size(columns: usize, rows: usize) -> usize {
+    (PICTURE_SIZE / columns.max(rows).max(1)).clamp(1, 16)
+}
+
+/// One square per cell that `fill` gives a colour
+fn cells(
+    columns: usize,
+    rows: usize,
+    size: usize,
+    fill: impl Fn(usize, usize) -> Option<String>,
+) -> String {
+    let mut body = String::new();
+    for row in 0..rows {
+        for column in 0..columns {
+            if let Some(color) = fill(row, column) {
+                writeln!(
+                    body,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{size}\" height=\"{size}\" fill=\"{color}\"/>",
+                    column * size,
+                    row * size
+                )
+                .unwrap();
+            }
+        }
+    }
+    body
+}
+
+fn grid_picture(
+    columns: usize,
+    rows: usize,
+    fill: impl Fn(usize, usize) -> Option<String>,
+) -> String {
+    let size = cell_size(columns, rows);
+    svg(
+        columns * size,
+        rows * size,
+        &cells(columns, rows, size, fill),
+    )
+}
+
+fn visibility_map(puzzle: &str) -> String {
+    let trees = day8::day8_generator(puzzle);
+    let visible = day8::visibility(&trees);
+    let columns = trees.first().map_or(0, Vec::len);
+
+    grid_picture(columns, trees.len(), |i, j| {
+        let height = trees[i][j];
+        Some(if visible[i][j] {
+            format!("hsl(120, 60%, {}%)", 20 + 5 * height)
+        } else {
+            format!("hsl(0, 0%, {}%)", 10 + 4 * height)
+        })
+    })
+}
+
+fn crt(puzzle: &str) -> String {
+    let program = day10::day10_generator(puzzle);
+    let mut cpu = day10::Cpu::new(&program);
+    cpu.run();
+
+    let screen = cpu.rows().collect::<Vec<_>>();
+    let columns = screen.first().map_or(0, |row| row.len());
+    grid_picture(columns, screen.len(), |i, j| {
+        let lit = screen[i].get(j).copied().unwrap_or(false);
+        Some(if lit { "#ffcc00" } else { "#1b1b1b" }.to_string())
+    })
+}
+
+fn climb(puzzle: &str) -> String {
+    let map = day12::day12_generator(puzzle);
+    let heights = day12::heights(&map);
+    let (_, path) = day12::shortest_path(&map);
+    let columns = heights.first().map_or(0, Vec::len);
+    let size = cell_size(columns, heights.len());
+
+    let mut body = cells(columns, heights.len(), size, |i, j| {
+        Some(format!(
+            "hsl(30, 40%, {}%)",
+            15 + 3 * heights[i][j] as usize
+        ))
+    });
+    let points = path
+        .iter()
+        .map(|&(i, j)| format!("{},{}", j * size + size / 2, i * size + size / 2))
+        .collect::<Vec<_>>()
+        .join(" ");
+    writeln!(
+        body,
+        "<polyline points=\"{points}\" fill=\"none\" stroke=\"#e03030\" stroke-width=\"{}\"/>",
+        (size / 3).max(1)
+    )
+    .unwrap();
+
+    svg(columns * size, heights.len() * size, &body)
+}
+
+fn cave(puzzle: &str) -> String {
+    let lines = day14::day14_generator(puzzle);
+    let mut cave = day14::SandCave::new(&lines, false);
+    cave.run();
+
+    let grid = cave.grid();
+    let columns = grid.first().map_or(0, Vec::len);
+    let source = cave.source();
+    grid_picture(columns, grid.len(), |i, j| match grid[i][j] {
+        Some(day14::Tile::Wall) => Some("#555".to_string()),
+        Some(day14::Tile::Sand) => Some("#e0b030".to_string()),
+        None if i == 0 && j == source => Some("#3070e0".to_string()),
+        None => None,
+    })
+}
+
+// Bars of how many simulated plays scored in each range
+fn score_histogram(luck: &day2::Distribution) -> String {
+    const BARS: u32 = 20;
+    const BAR: usize = 24;
+    const HEIGHT: usize = 120;
+
+    let (low, high) = (luck.quantile(0.0), luck.quantile(1.0));
+    let step = (high - low) / BARS + 1;
+    let mut counts = vec![0; ((high - low) / step) as usize + 1];
+    for &score in &luck.scores {
+        counts[((score - low) / step) as usize] += 1;
+    }
+    let most = counts.iter().copied().max().unwrap_or(0).max(1);
+
+    let mut body = String::new();
+    for (i, &count) in counts.iter().enumerate() {
+        let height = count * HEIGHT / most;
+        let start = low + i as u32 * step;
+        writeln!(
+            body,
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{height}\" fill=\"#3070e0\">\
+             <title>{start}..{}: {count} plays</title></rect>",
+            i * BAR,
+            HEIGHT - height,
+            BAR - 2,
+            start + step
+        )
+        .unwrap();
+    }
+    svg(counts.len() * BAR, HEIGHT, &body)
+}
+
+fn strategy_tables(puzzle: &str) -> Result<String, StrategyError> {
+    let guide = day2::day2_generator(puzzle);
+    let game = day2::Game::configured()?;
+
+    let mut html = String::from("<table>\n<tr><th>Second column</th><th>Total</th></tr>\n");
+    for mapping in day2::rank_mappings(&game, &guide)? {
+        let letters = mapping
+            .letters
+            .iter()
+            .map(|(letter, meaning)| format!("{letter}={meaning}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td></tr>",
+            escape(&letters),
+            mapping.total
+        )
+        .unwrap();
+    }
+    html.push_str("</table>\n");
+
+    let seed = day2::seed();
+    for (reading, name) in [(Reading::Shape, "shapes"), (Reading::Outcome, "outcomes")] {
+        let luck = day2::simulate(&game, &guide, reading, day2::trials(), seed)?;
+        writeln!(
+            html,
+            "<p>Second column as {name} against a random opponent (seed {seed}): {luck}</p>"
+        )
+        .unwrap();
+        html.push_str(&score_histogram(&luck));
+    }
+    Ok(html)
+}
+
+// Every reading of the guide's second column, and how luck plays into each
+fn strategy(puzzle: &str) -> String {
+    strategy_tables(puzzle)
+        .unwrap_or_else(|e| format!("<p class=\"error\">{}</p>\n", escape(&e.to_string())))
+}
+
+/// A picture of `day` on the given puzzle input, for days that have one
+pub fn picture(day: u32, puzzle: &str) -> Option<String> {
+    match day {
+        2 => Some(strategy(puzzle)),
+        8 => Some(visibility_map(puzzle)),
+        10 => Some(crt(puzzle)),
+        12 => Some(climb(puzzle)),
+        14 => Some(cave(puzzle)),
+        _ => None,
+    }
+}
+
+/// Horizontal bars of every part's time on a log scale, since the slowest
+/// parts take several orders of magnitude longer than the rest
+fn timing_chart(days: &[DayReport]) -> String {
+    const LABEL: usize = 110;
+    const BAR: usize = 420;
+    const ROW: usize = 20;
+
+    let micros = |elapsed: Duration| (elapsed.as_secs_f64() * 1e6).max(1.0);
+    let slowest = days
+        .iter()
+        .flat_map(|day| &day.parts)
+        .map(|part| micros(part.elapsed))
+        .fold(10.0, f64::max);
+
+    let mut body = String::new();
+    let mut y = 0;
+    for day in days {
+        for (part, result) in day.parts.iter().enumerate() {
+            let width = (micros(result.elapsed).log10() / slowest.log10() * BAR as f64).max(1.0);
+            writeln!(
+                body,
+                "<text x=\"0\" y=\"{}\" font-size=\"12\">day {} part {}</text>\
+                 <rect x=\"{LABEL}\" y=\"{}\" width=\"{width:.1}\" height=\"{}\" class=\"{}\" fill=\"currentColor\"/>\
+                 <text x=\"{}\" y=\"{}\" font-size=\"12\">{:.2?}</text>",
+                y + 14,
+                day.day,
+                part + 1,
+                y + 3,
+                ROW - 6,
+                result.status().class(),
+                LABEL as f64 + width + 6.0,
+                y + 14,
+                result.elapsed
+            )
+            .unwrap();
+            y += ROW;
+        }
+    }
+
+    svg(LABEL + BAR + 100, y, &body)
+}
+
+fn answer_cell(part: &Part) -> String {
+    let status = part.status();
+    let shown = match &part.answer {
+        Ok(answer) => format!("<pre>{}</pre>", escape(answer)),
+        Err(e) => format!("error: {}", escape(e)),
+    };
+    let note = match (status, &part.known) {
+        (Status::Correct, _) => " ✓".to_string(),
+        (Status::Wrong, Some(known)) => format!("<br>expected <pre>{}</pre>", escape(known)),
+        _ => String::new(),
+    };
+    format!("<td class=\"{}\">{shown}{note}</td>", status.class())
+}
+
+/// A self-contained HTML page with the answers, timings and pictures of `days`
+pub fn render(year: u32, days: &[DayReport]) -> String {
+    let parts = days.iter().flat_map(|day| &day.parts);
+    let checked = parts.clone().filter(|p| p.known.is_some()).count();
+    let correct = parts
+        .clone()
+        .filter(|p| p.status() == Status::Correct)
+        .count();
+    let total = parts.map(|p| p.elapsed).sum::<Duration>();
+
+    let mut html = String::new();
+    writeln!(
+        html,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code {year}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n\
+         <h1>Advent of Code {year}</h1>\n\
+         <p>{correct} of {checked} known answers correct, {total:.2?} in total</p>"
+    )
+    .unwrap();
+
+    html.push_str("<table>\n<tr><th>Day</th><th>Part 1</th><th>Part 2</th><th>Time</th></tr>\n");
+    for day in days {
+        let answers = day.parts.iter().map(answer_cell).collect::<String>();
+        let elapsed = day.parts.iter().map(|p| p.elapsed).sum::<Duration>();
+        let label = match day.picture {
+            Some(_) => format!("<a href=\"#day{0}\">{0}</a>", day.day),
+            None => day.day.to_string(),
+        };
+        writeln!(
+            html,
+            "<tr><td>{label}</td>{answers}<td>{elapsed:.2?}</td></tr>"
+        )
+        .unwrap();
+    }
+    html.push_str("</table>\n");
+
+    html.push_str("<h2>Timings</h2>\n");
+    html.push_str(&timing_chart(days));
+
+    for day in days {
+        if let Some(picture) = &day.picture {
+            writeln!(html, "<h2 id=\"day{0}\">Day {0}</h2>", day.day).unwrap();
+            html.push_str(picture);
+        }
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
@@ -0,0 +1,88 @@
+use crate::allocations::Allocations;
+use std::error::Error;
+
+pub type Solution = Result<String, Box<dyn Error + Send + Sync>>;
+
+pub trait IntoSolution {
+    fn into_solution(self) -> Solution;
+}
+
+macro_rules! plain_answers {
+    ($($t:ty),*) => {
+        $(impl IntoSolution for $t {
+            fn into_solution(self) -> Solution {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+plain_answers!(i32, i64, u32, u64, usize, String);
+
+impl<T: IntoSolution, E: Error + Send + Sync + 'static> IntoSolution for Result<T, E> {
+    fn into_solution(self) -> Solution {
+        self.map_err(Box::from).and_then(T::into_solution)
+    }
+}
+
+/// A puzzle day the runner knows how to solve
+pub struct Day {
+    pub day: u32,
+    pub parts: [fn(&str) -> Solution; 2],
+    /// Allocations of the generator and of each part, solving them once more
+    pub allocations: fn(&str) -> [Allocations; 3],
+}
+
+/// Registry entry for a day module with the usual generator and `solve_part1`/`solve_part2`
+///
+/// The generator may return data borrowed from the input. cargo-aoc cannot keep
+/// such data, so those days give it `#[aoc]` functions that parse for themselves.
+macro_rules! day {
+    ($day:literal, $module:ident, $generator:ident) => {
+        $crate::runner::Day {
+            day: $day,
+            parts: [
+                |input| {
+                    $crate::runner::IntoSolution::into_solution($module::solve_part1(
+                        &$module::$generator(input),
+                    ))
+                },
+                |input| {
+                    $crate::runner::IntoSolution::into_solution($module::solve_part2(
+                        &$module::$generator(input),
+                    ))
+                },
+            ],
+            allocations: |input| {
+                let (parsed, generator) =
+                    $crate::allocations::measure(|| $module::$generator(input));
+                let (_, part1) = $crate::allocations::measure(|| $module::solve_part1(&parsed));
+                let (_, part2) = $crate::allocations::measure(|| $module::solve_part2(&parsed));
+                [generator, part1, part2]
+            },
+        }
+    };
+}
+
+pub(crate) use day;
+
+/// The year cargo-aoc runs, see `aoc_lib!` in lib.rs
+pub const AOC_YEAR: u32 = 2022;
+
+pub const YEARS: &[(u32, &[Day])] = &[(2022, crate::year2022::DAYS)];
+
+/// Most recent year with any solutions
+pub fn latest_year() -> u32 {
+    YEARS.iter().map(|&(year, _)| year).max().unwrap()
+}
+
+pub fn days(year: u32) -> Option<&'static [Day]> {
+    YEARS
+        .iter()
+        .find(|&&(y, _)| y == year)
+        .map(|&(_, days)| days)
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    days(year)?.iter().find(|d| d.day == day)
+}
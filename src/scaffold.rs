@@ -0,0 +1,219 @@
+use crate::answers;
+use crate::runner;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// Source of a new day module
+///
+/// cargo-aoc only runs one year, so other years get no `#[aoc]` attributes.
+pub fn module(year: u32, day: u32) -> String {
+    let source = TEMPLATE.replace("{{day}}", &day.to_string());
+    if year == runner::AOC_YEAR {
+        return source;
+    }
+
+    source
+        .lines()
+        .filter(|line| !line.starts_with("#[aoc") && !line.starts_with("use aoc_runner_derive"))
+        .map(|line| format!("{line}\n"))
+        .collect()
+}
+
+/// Source of a new year module holding its first `day`, which `day!` needs to be used
+pub fn year_module(day: u32) -> String {
+    let empty = "use crate::runner::{day, Day};\n\npub const DAYS: &[Day] = &[];\n";
+    register_day(&register_module(empty, day), day)
+}
+
+/// Insert `{prefix}{name};` among the lines starting with `prefix`, keeping them sorted
+fn insert_declaration(source: &str, prefix: &str, name: &str) -> String {
+    let declaration = format!("{prefix}{name};");
+    let mut lines = source.lines().collect::<Vec<_>>();
+    let declarations = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with(prefix))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    let at = declarations
+        .iter()
+        .find(|&&i| lines[i][prefix.len()..].trim_end_matches(';') > name)
+        .copied()
+        .or_else(|| declarations.last().map(|&i| i + 1));
+    match at {
+        Some(at) => lines.insert(at, &declaration),
+        None => {
+            // First declaration goes in its own paragraph before any items
+            let at = lines
+                .iter()
+                .position(|line| line.starts_with("pub "))
+                .unwrap_or(lines.len());
+            lines.splice(at..at, [declaration.as_str(), ""]);
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Insert `pub mod dayN;` into the module list of a year module, keeping it sorted
+pub fn register_module(year_module: &str, day: u32) -> String {
+    insert_declaration(year_module, "pub mod ", &format!("day{day}"))
+}
+
+/// Insert `pub mod yearN;` into the module list of `lib.rs`
+pub fn register_year(lib: &str, year: u32) -> String {
+    insert_declaration(lib, "pub mod ", &format!("year{year}"))
+}
+
+/// Append `entry` to the array assigned to `name`, one element per line
+fn append_to_array(source: &str, name: &str, entry: &str) -> String {
+    let start = source
+        .find(&format!("pub const {name}"))
+        .unwrap_or_else(|| panic!("no {name} array"));
+    let open = start + source[start..].find("= &[").expect("not an array") + "= &[".len();
+    let close = open + source[open..].find("];").expect("unterminated array");
+
+    let elements = source[open..close].trim().trim_end_matches(',');
+    let mut array = String::from("\n");
+    for element in elements.lines().chain([entry]) {
+        let element = element.trim().trim_end_matches(',');
+        if !element.is_empty() {
+            array.push_str(&format!("    {element},\n"));
+        }
+    }
+
+    format!("{}{array}{}", &source[..open], &source[close..])
+}
+
+/// Add the day to a year module's list of days, keeping it in order
+pub fn register_day(year_module: &str, day: u32) -> String {
+    let entry = format!("day!({day}, day{day}, day{day}_generator)");
+    let number = |line: &str| {
+        line.trim_start()
+            .strip_prefix("day!(")
+            .and_then(|rest| rest.split(',').next())
+            .and_then(|n| n.parse::<u32>().ok())
+    };
+
+    let mut lines = year_module.lines().collect::<Vec<_>>();
+    match lines.iter().position(|line| number(line) > Some(day)) {
+        Some(at) => {
+            let entry = format!("    {entry},");
+            lines.insert(at, &entry);
+            lines.join("\n") + "\n"
+        }
+        None => append_to_array(year_module, "DAYS", &entry),
+    }
+}
+
+/// Add the year to the runner's list of years
+pub fn register_runner(runner: &str, year: u32) -> String {
+    append_to_array(
+        runner,
+        "YEARS",
+        &format!("({year}, crate::year{year}::DAYS)"),
+    )
+}
+
+/// An `answers.toml` section with nothing filled in yet
+pub fn answers_entry(year: u32, day: u32) -> String {
+    format!("[{year}.day{day}]\n# part1 =\n# part2 =\n")
+}
+
+fn update(path: PathBuf, edit: impl FnOnce(&str) -> String) -> io::Result<PathBuf> {
+    let source = fs::read_to_string(&path)?;
+    fs::write(&path, edit(&source))?;
+    Ok(path)
+}
+
+/// Create and register a module for `day` of `year` under `root`, returning the files touched
+pub fn new_day(root: &Path, year: u32, day: u32) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("there is no day {day}, puzzles run from day 1 to 25"),
+        ));
+    }
+
+    let year_dir = root.join(format!("src/year{year}"));
+    let module_path = year_dir.join(format!("day{day}.rs"));
+    if module_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module_path.display()),
+        ));
+    }
+
+    let mut touched = vec![];
+    let year_path = year_dir.join("mod.rs");
+    if year_path.exists() {
+        fs::write(&module_path, module(year, day))?;
+        touched.push(module_path);
+        touched.push(update(year_path, |source| {
+            register_day(&register_module(source, day), day)
+        })?);
+    } else {
+        fs::create_dir_all(&year_dir)?;
+        fs::write(&module_path, module(year, day))?;
+        touched.push(module_path);
+        fs::write(&year_path, year_module(day))?;
+        touched.push(year_path);
+        touched.push(update(root.join("src/lib.rs"), |lib| {
+            register_year(lib, year)
+        })?);
+        touched.push(update(root.join("src/runner.rs"), |runner| {
+            register_runner(runner, year)
+        })?);
+    }
+
+    let answers_path = root.join(answers::DEFAULT_PATH);
+    let mut known = match fs::read_to_string(&answers_path) {
+        Ok(known) => known,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    let entry = answers_entry(year, day);
+    if !known.lines().any(|line| Some(line) == entry.lines().next()) {
+        if !known.is_empty() {
+            known.push('\n');
+        }
+        known.push_str(&entry);
+        fs::write(&answers_path, known)?;
+        touched.push(answers_path);
+    }
+
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_year_sits_with_the_public_modules() {
+        let lib = "use aoc_runner_derive::aoc_lib;\n\npub mod answers;\n\npub mod year2022;\n";
+        assert_eq!(
+            register_year(lib, 2023),
+            "use aoc_runner_derive::aoc_lib;\n\npub mod answers;\n\npub mod year2022;\npub mod year2023;\n"
+        );
+    }
+
+    #[test]
+    fn new_year_module_uses_its_imports() {
+        assert_eq!(
+            year_module(3),
+            "use crate::runner::{day, Day};\n\npub mod day3;\n\npub const DAYS: &[Day] = &[\n    day!(3, day3, day3_generator),\n];\n"
+        );
+    }
+
+    #[test]
+    fn days_outside_december_are_rejected() {
+        let root = Path::new("/nonexistent");
+        let error = new_day(root, 2023, 26).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,146 @@
+use num::Zero;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Everything a search learned about the nodes it reached
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    pub distances: HashMap<N, C>,
+    pub parents: HashMap<N, N>,
+    // First node the search stopped at, if any
+    pub goal: Option<N>,
+}
+
+impl<N: Copy + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        Paths {
+            distances: HashMap::from([(start, zero)]),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, node: N) -> Option<C> {
+        self.distances.get(&node).copied()
+    }
+
+    /// Nodes from the start to `node`, inclusive
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(&node) {
+            return None;
+        }
+
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(&parent) = self.parents.get(&current) {
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Distance and path to the node the search stopped at
+    pub fn goal_path(&self) -> Option<(C, Vec<N>)> {
+        let goal = self.goal?;
+        Some((self.distance(goal)?, self.path_to(goal)?))
+    }
+}
+
+/// Breadth-first search over unit-cost edges, stopping at the first node that satisfies `is_goal`
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(start, 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+
+        let distance = paths.distances[&node] + 1;
+        for next in neighbors(&node) {
+            if let Entry::Vacant(entry) = paths.distances.entry(next) {
+                entry.insert(distance);
+                paths.parents.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm, stopping at the first node that satisfies `is_goal`
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Copy + Ord + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbors, |_| C::zero(), is_goal)
+}
+
+/// A* search, stopping at the first node that satisfies `is_goal`
+///
+/// `heuristic` must never overestimate the remaining cost for the distances to be exact.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Copy + Ord + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new(start, C::zero());
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::zero(), start))]);
+
+    while let Some(Reverse((_, distance, node))) = queue.pop() {
+        if distance > paths.distances[&node] {
+            // Already reached through a shorter route
+            continue;
+        }
+
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+
+        for (next, cost) in neighbors(&node) {
+            let next_distance = distance + cost;
+            let shorter = match paths.distances.get(&next) {
+                Some(&known) => next_distance < known,
+                None => true,
+            };
+            if shorter {
+                paths.distances.insert(next, next_distance);
+                paths.parents.insert(next, node);
+                queue.push(Reverse((
+                    next_distance + heuristic(&next),
+                    next_distance,
+                    next,
+                )));
+            }
+        }
+    }
+
+    paths
+}
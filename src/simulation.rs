@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+/// A puzzle whose state advances one discrete step at a time
+pub trait Simulation {
+    type Snapshot<'a>: Display
+    where
+        Self: 'a;
+
+    /// Advance the state by a single step
+    fn step(&mut self);
+
+    /// True once there are no more steps to take
+    fn is_done(&self) -> bool;
+
+    /// A printable view of the current state
+    fn snapshot(&self) -> Self::Snapshot<'_>;
+
+    /// Step until `stop` holds or there are no steps left, returning the number of steps taken
+    fn run_until(&mut self, mut stop: impl FnMut(&Self) -> bool) -> usize
+    where
+        Self: Sized,
+    {
+        let mut steps = 0;
+        while !self.is_done() && !stop(self) {
+            self.step();
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Step until there are no steps left
+    fn run(&mut self) -> usize
+    where
+        Self: Sized,
+    {
+        self.run_until(|_| false)
+    }
+}
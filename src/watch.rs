@@ -0,0 +1,85 @@
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Polls a set of files for modification
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    stamps: Vec<Option<SystemTime>>,
+    interval: Duration,
+}
+
+fn modified(path: &PathBuf) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>, interval: Duration) -> Self {
+        let stamps = paths.iter().map(modified).collect();
+        Watcher {
+            paths,
+            stamps,
+            interval,
+        }
+    }
+
+    /// Block until at least one file changes, returning the ones that did
+    pub fn wait(&mut self) -> Vec<&PathBuf> {
+        loop {
+            thread::sleep(self.interval);
+
+            let stamps = self.paths.iter().map(modified).collect::<Vec<_>>();
+            if stamps != self.stamps {
+                let changed = (0..self.paths.len())
+                    .filter(|&i| stamps[i] != self.stamps[i])
+                    .map(|i| &self.paths[i])
+                    .collect();
+                self.stamps = stamps;
+                return changed;
+            }
+        }
+    }
+}
+
+/// Answers from the output of the `run` command
+///
+/// Each answer follows `part N: ` and ends with its timing in parentheses, so
+/// multi-line answers and anything a solver prints along the way can be told apart.
+pub fn parse_answers(output: &str) -> [Option<String>; 2] {
+    let timed = |line: &str| line.ends_with(')') && line.contains(" (");
+
+    let mut answers = [None, None];
+    let mut lines = output.lines();
+    while let Some(line) = lines.next() {
+        let Some((part, first)) = line
+            .trim_start()
+            .strip_prefix("part ")
+            .and_then(|rest| rest.split_once(": "))
+        else {
+            continue;
+        };
+
+        let mut answer = first.to_string();
+        while !timed(&answer) {
+            match lines.next() {
+                Some(line) => {
+                    answer.push('\n');
+                    answer.push_str(line);
+                }
+                None => break,
+            }
+        }
+        if let Some((untimed, _)) = answer.rsplit_once(" (") {
+            answer.truncate(untimed.len());
+        }
+
+        match part {
+            "1" => answers[0] = Some(answer),
+            "2" => answers[1] = Some(answer),
+            _ => {}
+        }
+    }
+
+    answers
+}
@@ -0,0 +1,289 @@
+use crate::config;
+use crate::explain::{self, step};
+use aoc_runner_derive::{aoc, aoc_generator};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::io::{self, BufRead};
+use std::num::NonZeroU64;
+
+/// One elf's food, counting elves from 1 in the order they appear
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub items: Vec<u32>,
+}
+
+impl Elf {
+    pub fn calories(&self) -> u64 {
+        self.items.iter().map(|&calories| u64::from(calories)).sum()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+}
+
+type Input = Inventory;
+
+fn invalid(line: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("not a calorie count: {line:?}"),
+    )
+}
+
+/// Every elf's food from `reader`, one elf at a time
+///
+/// Only the elf being read is held in memory, so inputs of any size can be streamed.
+/// Runs of blank lines separate elves like a single one does.
+pub fn elves(reader: impl BufRead) -> impl Iterator<Item = io::Result<Elf>> {
+    let mut lines = reader.lines();
+    let mut index = 0;
+    let mut done = false;
+
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+
+        let mut items = vec![];
+        loop {
+            match lines.next() {
+                Some(Ok(line)) if line.trim().is_empty() => {
+                    if items.is_empty() {
+                        continue;
+                    }
+                    break;
+                }
+                Some(Ok(line)) => match line.trim().parse() {
+                    Ok(calories) => items.push(calories),
+                    Err(_) => {
+                        done = true;
+                        return Some(Err(invalid(&line)));
+                    }
+                },
+                Some(Err(e)) => {
+                    done = true;
+                    return Some(Err(e));
+                }
+                None => {
+                    done = true;
+                    if items.is_empty() {
+                        return None;
+                    }
+                    break;
+                }
+            }
+        }
+
+        index += 1;
+        Some(Ok(Elf { index, items }))
+    })
+}
+
+/// The `k` elves carrying the most calories as `(calories, index)`, most first, in O(k) memory
+pub fn top_from_reader(reader: impl BufRead, k: usize) -> io::Result<Vec<(u64, usize)>> {
+    let mut top = BinaryHeap::with_capacity(k + 1);
+    for elf in elves(reader) {
+        let elf = elf?;
+        top.push(Reverse((elf.calories(), elf.index)));
+        if top.len() > k {
+            top.pop();
+        }
+    }
+
+    Ok(top.into_sorted_vec().into_iter().map(|r| r.0).collect())
+}
+
+impl Inventory {
+    pub fn read(reader: impl BufRead) -> io::Result<Self> {
+        Ok(Inventory {
+            elves: elves(reader).collect::<io::Result<_>>()?,
+        })
+    }
+
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    /// The `k` elves carrying the most calories, most first
+    ///
+    /// Ties go to the elf that came later, as the puzzle does not say.
+    pub fn top(&self, k: usize) -> Vec<&Elf> {
+        let mut elves = self.elves.iter().collect::<Vec<_>>();
+        elves.sort_by_cached_key(|elf| Reverse((elf.calories(), elf.index)));
+        elves.truncate(k);
+        elves
+    }
+
+    /// Median of the calories carried, halfway between the middle two for an even count
+    pub fn median(&self) -> Option<f64> {
+        let mut calories = self.elves.iter().map(Elf::calories).collect::<Vec<_>>();
+        calories.sort_unstable();
+
+        let middle = calories.len() / 2;
+        match calories.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(calories[middle] as f64),
+            _ => Some((calories[middle - 1] as f64 + calories[middle] as f64) / 2.0),
+        }
+    }
+
+    /// How many elves carry calories in each bucket of `width`, keyed by the bucket's start
+    pub fn histogram(&self, width: NonZeroU64) -> BTreeMap<u64, usize> {
+        let width = width.get();
+        let mut buckets = BTreeMap::new();
+        for elf in &self.elves {
+            *buckets.entry(elf.calories() / width * width).or_insert(0) += 1;
+        }
+        buckets
+    }
+
+    /// Elves carrying more than `threshold` calories, in order
+    pub fn above(&self, threshold: u64) -> impl Iterator<Item = &Elf> {
+        self.elves
+            .iter()
+            .filter(move |elf| elf.calories() > threshold)
+    }
+}
+
+#[aoc_generator(day1)]
+pub fn day1_generator(input: &str) -> Input {
+    Inventory::read(input.as_bytes()).unwrap()
+}
+
+// Sums up how the calories are spread over the elves
+fn explain_spread(input: &Input) {
+    if let Some(median) = input.median() {
+        let above = input.above(median as u64).count();
+        step!("median {median} calories, {above} elves carry more");
+    }
+
+    let Some(width) = NonZeroU64::new(config::param(1, "bucket", 10_000)) else {
+        step!("no histogram, buckets must be at least 1 calorie wide");
+        return;
+    };
+    for (start, elves) in input.histogram(width) {
+        step!("{start}..{}: {elves} elves", start + width.get());
+    }
+}
+
+#[aoc(day1, part1)]
+pub fn solve_part1(input: &Input) -> u64 {
+    if explain::enabled() {
+        explain_spread(input);
+    }
+
+    let Some(elf) = input.top(1).into_iter().next() else {
+        step!("no elves, so no calories");
+        return 0;
+    };
+    let calories = elf.calories();
+    step!("elf {} carries the most, {calories} calories", elf.index);
+    calories
+}
+
+#[aoc(day1, part2)]
+pub fn solve_part2(input: &Input) -> u64 {
+    let mut sum = 0;
+
+    for (rank, elf) in input.top(3).into_iter().enumerate() {
+        let top = elf.calories();
+        step!("#{}: elf {} with {top} calories", rank + 1, elf.index);
+        sum += top;
+    }
+
+    sum
+}
+
+// Keeps only the top three while reading, as for inputs too big to hold
+#[aoc_generator(day1, part2, Streaming)]
+fn top_three(input: &str) -> Vec<(u64, usize)> {
+    top_from_reader(input.as_bytes(), 3).unwrap()
+}
+
+#[aoc(day1, part2, Streaming)]
+fn part2_streaming(top: &[(u64, usize)]) -> u64 {
+    top.iter().map(|&(calories, _)| calories).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(&day1_generator(EXAMPLE)), 24000);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(&day1_generator(EXAMPLE)), 45000);
+    }
+
+    #[test]
+    fn blank_runs_separate_elves_once() {
+        let inventory = day1_generator("\n\n1\n\n\n\n2\n3\n\n");
+        let elves = inventory
+            .elves()
+            .iter()
+            .map(|elf| (elf.index, elf.calories()))
+            .collect::<Vec<_>>();
+        assert_eq!(elves, [(1, 1), (2, 5)]);
+    }
+
+    #[test]
+    fn calories_add_up_past_u32() {
+        let inventory = day1_generator("4294967295\n4294967295");
+        assert_eq!(solve_part1(&inventory), 2 * u32::MAX as u64);
+    }
+
+    #[test]
+    fn statistics() {
+        let inventory = day1_generator(EXAMPLE);
+        assert_eq!(inventory.median(), Some(10000.0));
+        assert_eq!(
+            inventory.histogram(NonZeroU64::new(10000).unwrap()),
+            BTreeMap::from([(0, 2), (10000, 2), (20000, 1)])
+        );
+        let above = inventory.above(10000).map(|elf| elf.index);
+        assert_eq!(above.collect::<Vec<_>>(), [3, 4]);
+
+        let even = day1_generator("1\n\n2\n\n3\n\n4");
+        assert_eq!(even.median(), Some(2.5));
+        assert_eq!(day1_generator("").median(), None);
+    }
+
+    #[test]
+    fn streaming_matches_the_inventory() {
+        let top = top_from_reader(EXAMPLE.as_bytes(), 3).unwrap();
+        assert_eq!(top, [(24000, 4), (11000, 3), (10000, 5)]);
+        let inventory = day1_generator(EXAMPLE);
+        let indices = inventory
+            .top(3)
+            .iter()
+            .map(|elf| elf.index)
+            .collect::<Vec<_>>();
+        assert_eq!(indices, [4, 3, 5]);
+
+        let error = top_from_reader("1\nlots".as_bytes(), 1).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::config;
+use crate::parse;
+use crate::simulation::Simulation;
+use aoc_runner_derive::{aoc, aoc_generator};
+use nom::{branch::alt, bytes::complete::tag, character::complete, sequence::preceded, IResult};
+use std::fmt::{Display, Formatter};
+
+type Input = Vec<Instruction>;
+
+#[derive(Debug)]
+pub enum Instruction {
+    NoOp,
+    Addx(i32),
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    let (input, result) = alt((tag("noop"), tag("addx")))(input)?;
+    Ok(match result {
+        "noop" => (input, Instruction::NoOp),
+        "addx" => {
+            let (input, amount) = preceded(tag(" "), complete::i32)(input)?;
+
+            (input, Instruction::Addx(amount))
+        }
+        _ => unreachable!(),
+    })
+}
+
+#[aoc_generator(day10)]
+pub fn day10_generator(input: &str) -> Input {
+    parse::all(parse::lines(parse_instruction), input)
+}
+
+pub struct Cpu<'a> {
+    program: &'a [Instruction],
+    pc: usize,
+    // Second cycle of an `addx`
+    busy: bool,
+    cycle: usize,
+    x: i32,
+    signal_strength: i32,
+    crt: Vec<bool>,
+    width: usize,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(input: &'a Input) -> Self {
+        Cpu {
+            program: input,
+            pc: 0,
+            busy: false,
+            cycle: 0,
+            x: 1,
+            signal_strength: 0,
+            crt: vec![],
+            width: config::param(10, "crt_width", 40),
+        }
+    }
+
+    /// The pixels drawn so far, one row of the screen at a time
+    pub fn rows(&self) -> std::slice::Chunks<'_, bool> {
+        self.crt.chunks(self.width)
+    }
+
+    pub fn screen(&self) -> String {
+        self.crt
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &lit)| {
+                let pixel = if lit { '#' } else { ' ' };
+                if i % self.width == 0 {
+                    vec!['\n', pixel]
+                } else {
+                    vec![pixel]
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct Registers<'a>(&'a Cpu<'a>);
+
+impl<'a> Display for Registers<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let cpu = self.0;
+        writeln!(
+            f,
+            "cycle: {}, X: {}, signal strength: {}",
+            cpu.cycle, cpu.x, cpu.signal_strength
+        )?;
+        match cpu.program.get(cpu.pc) {
+            Some(Instruction::NoOp) => writeln!(f, "next: noop")?,
+            Some(Instruction::Addx(a)) if cpu.busy => writeln!(f, "next: addx {a} (finishing)")?,
+            Some(Instruction::Addx(a)) => writeln!(f, "next: addx {a}")?,
+            None => writeln!(f, "next: halted")?,
+        }
+        write!(
+            f,
+            "{}",
+            cpu.crt
+                .chunks(cpu.width)
+                .map(|row| row
+                    .iter()
+                    .map(|&lit| if lit { '#' } else { '.' })
+                    .collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    }
+}
+
+impl<'a> Simulation for Cpu<'a> {
+    type Snapshot<'b>
+        = Registers<'b>
+    where
+        Self: 'b;
+
+    fn step(&mut self) {
+        let column = (self.cycle % self.width) as i32;
+        self.crt.push((self.x - column).abs() <= 1);
+
+        if (self.cycle + 21).is_multiple_of(40) {
+            self.signal_strength += (self.cycle as i32 + 1) * self.x;
+        }
+
+        match self.program[self.pc] {
+            Instruction::NoOp => self.pc += 1,
+            Instruction::Addx(a) if self.busy => {
+                self.x += a;
+                self.busy = false;
+                self.pc += 1;
+            }
+            Instruction::Addx(_) => self.busy = true,
+        }
+
+        self.cycle += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot<'_> {
+        Registers(self)
+    }
+}
+
+#[aoc(day10, part1)]
+pub fn solve_part1(input: &Input) -> i32 {
+    let mut cpu = Cpu::new(input);
+    cpu.run();
+    cpu.signal_strength
+}
+
+#[aoc(day10, part2)]
+pub fn solve_part2(input: &Input) -> String {
+    let mut cpu = Cpu::new(input);
+    cpu.run();
+    cpu.screen()
+}
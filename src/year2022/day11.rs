@@ -0,0 +1,390 @@
+use crate::arith::{self, Overflow, OverflowError};
+use crate::config;
+use crate::cycle;
+use crate::dot;
+use crate::simulation::Simulation;
+use aoc_runner_derive::{aoc, aoc_generator};
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, line_ending, space0},
+    multi::separated_list1,
+    sequence::{preceded, tuple},
+    IResult,
+};
+use num::integer::lcm;
+use std::cmp::Reverse;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone)]
+pub enum Operation {
+    Add(u64),
+    Mult(u64),
+    Square,
+}
+
+impl Operation {
+    fn apply(self, worry: u64) -> Result<u64, Overflow> {
+        match self {
+            Operation::Add(n) => arith::add(n, worry),
+            Operation::Mult(n) => arith::mul(n, worry),
+            Operation::Square => arith::mul(worry, worry),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Operation::Add(n) => write!(f, "new = old + {n}"),
+            Operation::Mult(n) => write!(f, "new = old * {n}"),
+            Operation::Square => write!(f, "new = old * old"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    remainder: u64,
+    false_throw: usize,
+    true_throw: usize,
+    inspected: usize,
+}
+
+type Input = Vec<Monkey>;
+
+fn parse_square(input: &str) -> IResult<&str, Operation> {
+    let (input, _) = tag("* old")(input)?;
+    Ok((input, Operation::Square))
+}
+
+fn parse_add(input: &str) -> IResult<&str, Operation> {
+    let (input, n) = preceded(tag("+ "), complete::u64)(input)?;
+    Ok((input, Operation::Add(n)))
+}
+
+fn parse_multiply(input: &str) -> IResult<&str, Operation> {
+    let (input, n) = preceded(tag("* "), complete::u64)(input)?;
+    Ok((input, Operation::Mult(n)))
+}
+
+fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
+    let (input, _) = preceded(tag("Monkey "), complete::u8)(input)?;
+    let (input, items) = preceded(
+        tuple((tag(":"), line_ending, space0, tag("Starting items: "))),
+        separated_list1(tag(", "), complete::u64),
+    )(input)?;
+    let (input, operation) = preceded(
+        tuple((line_ending, space0, tag("Operation: new = old "))),
+        alt((parse_add, parse_multiply, parse_square)),
+    )(input)?;
+    let (input, remainder) = preceded(tag("\n  Test: divisible by "), complete::u64)(input)?;
+
+    let (input, true_throw) = preceded(
+        tuple((line_ending, space0, tag("If true: throw to monkey "))),
+        complete::u8,
+    )(input)?;
+    let (input, false_throw) = preceded(
+        tuple((line_ending, space0, tag("If false: throw to monkey "))),
+        complete::u8,
+    )(input)?;
+
+    let true_throw = true_throw as usize;
+    let false_throw = false_throw as usize;
+
+    Ok((
+        input,
+        Monkey {
+            items,
+            operation,
+            remainder,
+            true_throw,
+            false_throw,
+            inspected: 0,
+        },
+    ))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
+    separated_list1(tag("\n\n"), parse_monkey)(input)
+}
+
+#[aoc_generator(day11)]
+pub fn day11_generator(input: &str) -> Input {
+    parse_input(input).unwrap().1
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Relief {
+    // Worry is divided after every inspection
+    Divide(u64),
+    // Worry is kept below the common multiple of every monkey's test
+    Bounded,
+}
+
+pub struct KeepAway {
+    monkeys: Vec<Monkey>,
+    relief: Relief,
+    modulus: u64,
+    turn: usize,
+    round: usize,
+    rounds: usize,
+    overflow: Option<Overflow>,
+}
+
+impl KeepAway {
+    pub fn new(input: &Input, relief: Relief, rounds: usize) -> Self {
+        KeepAway {
+            monkeys: input.clone(),
+            relief,
+            modulus: input.iter().map(|m| m.remainder).fold(1, lcm),
+            turn: 0,
+            round: 0,
+            rounds,
+            overflow: None,
+        }
+    }
+
+    pub fn monkey_business(&self) -> Result<usize, Overflow> {
+        match &self.overflow {
+            Some(overflow) => Err(overflow.clone()),
+            None => Ok(self
+                .monkeys
+                .iter()
+                .map(|k| Reverse(k.inspected))
+                .k_smallest(2)
+                .map(|n| n.0)
+                .product()),
+        }
+    }
+}
+
+pub struct Troop<'a>(&'a KeepAway);
+
+impl<'a> Display for Troop<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let game = self.0;
+        write!(f, "round: {}, next turn: monkey {}", game.round, game.turn)?;
+        if let Some(overflow) = &game.overflow {
+            write!(
+                f,
+                "\noverflowed: {} {} {}",
+                overflow.lhs, overflow.operation, overflow.rhs
+            )?;
+        }
+        for (i, monkey) in game.monkeys.iter().enumerate() {
+            write!(
+                f,
+                "\nMonkey {i} (inspected {}): {}",
+                monkey.inspected,
+                monkey.items.iter().join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Simulation for KeepAway {
+    type Snapshot<'a> = Troop<'a>;
+
+    fn step(&mut self) {
+        let i = self.turn;
+        let new_items = match self.monkeys[i]
+            .items
+            .iter()
+            .map(|&x| self.monkeys[i].operation.apply(x))
+            .collect::<Result<Vec<u64>, Overflow>>()
+        {
+            Ok(items) => items,
+            Err(overflow) => {
+                self.overflow = Some(overflow);
+                return;
+            }
+        };
+        let (mut throw_true, mut throw_false): (Vec<u64>, Vec<u64>) = new_items
+            .into_iter()
+            .map(|n| match self.relief {
+                Relief::Divide(d) => n / d,
+                Relief::Bounded => n % self.modulus,
+            })
+            .partition(|&x| x % self.monkeys[i].remainder == 0);
+
+        let t = self.monkeys[i].true_throw;
+        let f = self.monkeys[i].false_throw;
+
+        self.monkeys[i].inspected += self.monkeys[i].items.len();
+        self.monkeys[i].items.clear();
+        self.monkeys[t].items.append(&mut throw_true);
+        self.monkeys[f].items.append(&mut throw_false);
+
+        self.turn += 1;
+        if self.turn == self.monkeys.len() {
+            self.turn = 0;
+            self.round += 1;
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.round >= self.rounds || self.overflow.is_some()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot<'_> {
+        Troop(self)
+    }
+}
+
+/// Rounds played in `part`
+pub fn rounds(part: u32) -> usize {
+    match part {
+        1 => config::param(11, "part1_rounds", 20),
+        _ => config::param(11, "part2_rounds", 10_000),
+    }
+}
+
+#[aoc(day11, part1)]
+pub fn solve_part1(input: &Input) -> Result<usize, OverflowError> {
+    let mut game = KeepAway::new(input, Relief::Divide(3), rounds(1));
+    game.run();
+    game.monkey_business().map_err(|e| e.during(11, 1))
+}
+
+/// Follow a single item through one round, recording which monkeys inspect it
+///
+/// Items never affect each other, so a round can be played one item at a time.
+/// An item thrown to a monkey later in the turn order is inspected again in the same round.
+fn item_round(
+    monkeys: &[Monkey],
+    modulus: u64,
+    (mut monkey, mut worry): (usize, u64),
+    inspected_by: &mut Vec<usize>,
+) -> Result<(usize, u64), Overflow> {
+    loop {
+        inspected_by.push(monkey);
+
+        let current = &monkeys[monkey];
+        worry = current.operation.apply(worry)? % modulus;
+
+        let next = if worry % current.remainder == 0 {
+            current.true_throw
+        } else {
+            current.false_throw
+        };
+
+        if next < monkey {
+            return Ok((next, worry));
+        }
+        monkey = next;
+    }
+}
+
+#[aoc(day11, part2)]
+pub fn solve_part2(input: &Input) -> Result<usize, OverflowError> {
+    let rounds = rounds(2);
+    let modulus = input.iter().map(|m| m.remainder).fold(1, lcm);
+    let mut inspected = vec![0; input.len()];
+
+    for start in input
+        .iter()
+        .enumerate()
+        .flat_map(|(i, m)| m.items.iter().map(move |&worry| (i, worry)))
+    {
+        // Monkeys inspecting the item in each round, and where each round starts in that list
+        let mut inspected_by = vec![];
+        let mut round_starts = vec![];
+        let mut overflow = None;
+        let (_, found) = cycle::hashed(
+            start,
+            |&state| {
+                round_starts.push(inspected_by.len());
+                item_round(input, modulus, state, &mut inspected_by).unwrap_or_else(|e| {
+                    // Repeating the current state ends the search straight away
+                    overflow = Some(e);
+                    state
+                })
+            },
+            rounds,
+        );
+        if let Some(overflow) = overflow {
+            return Err(overflow.during(11, 2));
+        }
+        round_starts.push(inspected_by.len());
+
+        // Running count of inspections by each monkey after every round
+        let mut running = vec![0; input.len()];
+        let mut history = vec![vec![0]; input.len()];
+        for round in round_starts.windows(2) {
+            for &monkey in &inspected_by[round[0]..round[1]] {
+                running[monkey] += 1;
+            }
+            for (counts, &count) in history.iter_mut().zip(&running) {
+                counts.push(count);
+            }
+        }
+
+        for (total, counts) in inspected.iter_mut().zip(&history) {
+            *total += match found {
+                Some(cycle) => cycle.extrapolate(counts, rounds),
+                None => counts[rounds],
+            };
+        }
+    }
+
+    Ok(inspected
+        .into_iter()
+        .map(Reverse)
+        .k_smallest(2)
+        .map(|n| n.0)
+        .product())
+}
+
+#[aoc(day11, part2, Simulation)]
+pub fn solve_part2_simulation(input: &Input) -> Result<usize, OverflowError> {
+    let mut game = KeepAway::new(input, Relief::Bounded, rounds(2));
+    game.run();
+    game.monkey_business().map_err(|e| e.during(11, 2))
+}
+
+/// Which monkey throws to which, with the two busiest monkeys of part 1 highlighted
+pub fn throw_graph(input: &Input) -> dot::Graph {
+    let mut game = KeepAway::new(input, Relief::Divide(3), rounds(1));
+    game.run();
+    let busiest = game
+        .monkeys
+        .iter()
+        .enumerate()
+        .map(|(i, m)| (Reverse(m.inspected), i))
+        .k_smallest(2)
+        .map(|(_, i)| i)
+        .collect::<Vec<_>>();
+
+    let mut graph = dot::Graph::new("day 11", true);
+    for (i, monkey) in input.iter().enumerate() {
+        let label = format!(
+            "Monkey {i}\n{}\ndivisible by {}",
+            monkey.operation, monkey.remainder
+        );
+        let mut attrs = vec![("label", label.as_str()), ("shape", "box")];
+        if busiest.contains(&i) {
+            attrs.extend(dot::HIGHLIGHT);
+        }
+        graph.node(&format!("Monkey {i}"), &attrs);
+    }
+    for (i, monkey) in input.iter().enumerate() {
+        let from = format!("Monkey {i}");
+        graph.edge(
+            &from,
+            &format!("Monkey {}", monkey.true_throw),
+            &[("label", "true")],
+        );
+        graph.edge(
+            &from,
+            &format!("Monkey {}", monkey.false_throw),
+            &[("label", "false"), ("style", "dashed")],
+        );
+    }
+
+    graph
+}
@@ -1,3 +1,4 @@
+use crate::search;
 use aoc_runner_derive::{aoc, aoc_generator};
 use nom::{
     character::complete::{line_ending, none_of},
@@ -41,68 +42,35 @@ pub fn day12_generator(input: &str) -> Input {
     parse_input(input).unwrap().1
 }
 
-#[aoc(day12, part1)]
-pub fn solve_part1(input: &Input) -> usize {
-    let start = input
-        .iter()
-        .enumerate()
-        .find_map(|(i, row)| {
-            row.iter().enumerate().find_map(|(j, &v)| {
-                if v.0 == Position::Start {
-                    Some((i, j))
-                } else {
-                    None
-                }
-            })
-        })
-        .expect("No start position");
-
-    let end = input
+fn find(input: &Input, position: Position) -> Option<(usize, usize)> {
+    input
         .iter()
         .enumerate()
-        .find_map(|(i, row)| {
-            row.iter().enumerate().find_map(|(j, &v)| {
-                if v.0 == Position::End {
-                    Some((i, j))
-                } else {
-                    None
-                }
-            })
-        })
-        .expect("No start position");
-
-    let input: Vec<Vec<u8>> = input
+        .find_map(|(i, row)| row.iter().position(|&(p, _)| p == position).map(|j| (i, j)))
+}
+
+pub fn heights(input: &Input) -> Vec<Vec<u8>> {
+    input
         .iter()
         .map(|row| row.iter().map(|v| v.1).collect())
-        .collect();
-
-    let mut edges = vec![];
-    for i in 0..input.len() {
-        for j in 0..input[i].len() {
-            let curr = input[i][j];
-
-            if i > 0 && input[i - 1][j] <= curr + 1 {
-                edges.push(((i, j), (i - 1, j)));
-            }
-            if j > 0 && input[i][j - 1] <= curr + 1 {
-                edges.push(((i, j), (i, j - 1)));
-            }
-            if i < input.len() - 1 && input[i + 1][j] <= curr + 1 {
-                edges.push(((i, j), (i + 1, j)));
-            }
-            if j < input[i].len() - 1 && input[i][j + 1] <= curr + 1 {
-                edges.push(((i, j), (i, j + 1)));
-            }
-        }
-    }
-
-    // edges represents the movement each node can make
-    let graph: GraphMap<(usize, usize), (), Directed> = DiGraphMap::from_edges(&edges);
+        .collect()
+}
 
-    // compute from end to start
-    let (len, path) =
-        astar(&graph, start, |stop| stop == end, |_| 1, |_| 1).expect("No path from start to end");
+fn adjacent(
+    input: &[Vec<u8>],
+    (i, j): (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    [
+        (i.wrapping_sub(1), j),
+        (i, j.wrapping_sub(1)),
+        (i + 1, j),
+        (i, j + 1),
+    ]
+    .into_iter()
+    .filter(|&(i, j)| i < input.len() && j < input[i].len())
+}
 
+fn print_path(input: &[Vec<u8>], path: &[(usize, usize)]) {
     let mut answer_string = vec![vec![' '; input[0].len()]; input.len()];
 
     let start = path.first().unwrap();
@@ -121,8 +89,6 @@ pub fn solve_part1(input: &Input) -> usize {
         };
         from = point;
     }
-    answer_string[end.0][end.1] = 'E';
-    answer_string[start.0][start.1] = 'S';
 
     answer_string[last.0][last.1] = 'E';
     answer_string[start.0][start.1] = 'S';
@@ -136,30 +102,104 @@ pub fn solve_part1(input: &Input) -> usize {
             .collect::<Vec<String>>()
             .join("\n")
     );
+}
+
+/// Length of the shortest climb from start to end, and the squares along it
+pub fn shortest_path(input: &Input) -> (usize, Vec<(usize, usize)>) {
+    let start = find(input, Position::Start).expect("No start position");
+    let end = find(input, Position::End).expect("No end position");
+    let input = heights(input);
+
+    search::astar(
+        start,
+        |&from| {
+            let curr = input[from.0][from.1];
+            adjacent(&input, from)
+                .filter(|&to| input[to.0][to.1] <= curr + 1)
+                .map(|to| (to, 1))
+                .collect::<Vec<_>>()
+        },
+        |&(i, j)| i.abs_diff(end.0) + j.abs_diff(end.1),
+        |&point| point == end,
+    )
+    .goal_path()
+    .expect("No path from start to end")
+}
+
+#[aoc(day12, part1)]
+pub fn solve_part1(input: &Input) -> usize {
+    let (len, path) = shortest_path(input);
+
+    print_path(&heights(input), &path);
 
     len
 }
 
 #[aoc(day12, part2)]
 pub fn solve_part2(input: &Input) -> usize {
-    let end = input
-        .iter()
-        .enumerate()
-        .find_map(|(i, row)| {
-            row.iter().enumerate().find_map(|(j, &v)| {
-                if v.0 == Position::End {
-                    Some((i, j))
-                } else {
-                    None
-                }
-            })
-        })
-        .expect("No starting position");
-
-    let input: Vec<Vec<u8>> = input
-        .iter()
-        .map(|row| row.iter().map(|v| v.1).collect())
-        .collect();
+    let end = find(input, Position::End).expect("No end position");
+    let input = heights(input);
+
+    // Walk backwards from the end to the nearest lowest point
+    search::bfs(
+        end,
+        |&from| {
+            let curr = input[from.0][from.1];
+            adjacent(&input, from)
+                .filter(|&to| input[to.0][to.1] + 1 >= curr)
+                .collect::<Vec<_>>()
+        },
+        |&(i, j)| input[i][j] == 0,
+    )
+    .goal_path()
+    .map(|(len, _)| len)
+    .expect("No path to the lowest point")
+}
+
+#[aoc(day12, part1, Petgraph)]
+pub fn solve_part1_petgraph(input: &Input) -> usize {
+    let start = find(input, Position::Start).expect("No start position");
+    let end = find(input, Position::End).expect("No end position");
+
+    let input = heights(input);
+
+    let mut edges = vec![];
+    for i in 0..input.len() {
+        for j in 0..input[i].len() {
+            let curr = input[i][j];
+
+            if i > 0 && input[i - 1][j] <= curr + 1 {
+                edges.push(((i, j), (i - 1, j)));
+            }
+            if j > 0 && input[i][j - 1] <= curr + 1 {
+                edges.push(((i, j), (i, j - 1)));
+            }
+            if i < input.len() - 1 && input[i + 1][j] <= curr + 1 {
+                edges.push(((i, j), (i + 1, j)));
+            }
+            if j < input[i].len() - 1 && input[i][j + 1] <= curr + 1 {
+                edges.push(((i, j), (i, j + 1)));
+            }
+        }
+    }
+
+    // edges represents the movement each node can make
+    let graph: GraphMap<(usize, usize), (), Directed> = DiGraphMap::from_edges(&edges);
+
+    // compute from end to start
+    let (len, path) =
+        astar(&graph, start, |stop| stop == end, |_| 1, |_| 1).expect("No path from start to end");
+
+    print_path(&input, &path);
+
+    len
+}
+
+#[aoc(day12, part2, Petgraph)]
+pub fn solve_part2_petgraph(input: &Input) -> usize {
+    let end = find(input, Position::End).expect("No end position");
+
+    let input = heights(input);
 
     let mut edges = vec![];
     for i in 0..input.len() {
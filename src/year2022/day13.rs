@@ -1,3 +1,4 @@
+use crate::explain::step;
 use aoc_runner_derive::{aoc, aoc_generator};
 use nom::{
     branch::alt,
@@ -9,6 +10,7 @@ use nom::{
     IResult,
 };
 use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Element {
@@ -41,6 +43,64 @@ impl PartialOrd for Element {
     }
 }
 
+impl Display for Element {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Element::Digit(n) => write!(f, "{n}"),
+            Element::List(list) => write!(f, "{}", Packet(list)),
+        }
+    }
+}
+
+struct Packet<'a>(&'a [Element]);
+
+impl<'a> Display for Packet<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "[")?;
+        for (i, element) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{element}")?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// The first difference between two lists, which decides how they compare
+fn reason(left: &[Element], right: &[Element]) -> String {
+    for (l, r) in left.iter().zip(right) {
+        if l.cmp(r) == Ordering::Equal {
+            continue;
+        }
+
+        return match (l, r) {
+            (Element::Digit(a), Element::Digit(b)) => {
+                format!("{a} {} {b}", if a < b { "<" } else { ">" })
+            }
+            (Element::List(a), Element::List(b)) => reason(a, b),
+            (Element::Digit(a), Element::List(b)) => {
+                format!(
+                    "{a} becomes [{a}], then {}",
+                    reason(std::slice::from_ref(l), b)
+                )
+            }
+            (Element::List(a), Element::Digit(b)) => {
+                format!(
+                    "{b} becomes [{b}], then {}",
+                    reason(a, std::slice::from_ref(r))
+                )
+            }
+        };
+    }
+
+    match left.len().cmp(&right.len()) {
+        Ordering::Less => "left runs out first".to_string(),
+        Ordering::Greater => "right runs out first".to_string(),
+        Ordering::Equal => "they are equal".to_string(),
+    }
+}
+
 type Input = Vec<(Vec<Element>, Vec<Element>)>;
 
 fn parse_list(input: &str) -> IResult<&str, Vec<Element>> {
@@ -76,7 +136,22 @@ pub fn solve_part1(input: &Input) -> usize {
     input
         .iter()
         .enumerate()
-        .filter(|(_, (left, right))| left.cmp(right) == Ordering::Less)
+        .filter(|(index, (left, right))| {
+            let ordered = left.cmp(right) == Ordering::Less;
+            step!(
+                "pair {}: {} {} {}, {}",
+                index + 1,
+                Packet(left),
+                if ordered {
+                    "is ordered before"
+                } else {
+                    "is not ordered before"
+                },
+                Packet(right),
+                reason(left, right)
+            );
+            ordered
+        })
         .map(|(index, _)| index + 1)
         .sum()
 }
@@ -101,6 +176,7 @@ pub fn solve_part2(input: &Input) -> usize {
         .enumerate()
         .filter_map(|(index, list)| {
             if list == two || list == six {
+                step!("divider {} sorts to position {}", Packet(&list), index + 1);
                 Some(index + 1)
             } else {
                 None
@@ -0,0 +1,255 @@
+use crate::simulation::Simulation;
+use aoc_runner_derive::{aoc, aoc_generator};
+use colored::Colorize;
+use itertools::{Itertools, MinMaxResult::MinMax};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Point(usize, usize);
+
+#[derive(Debug, Copy, Clone)]
+pub struct Line(Point, Point);
+
+type Input = Vec<Line>;
+
+fn parse_point(input: &str) -> IResult<&str, Point> {
+    let (input, point) = separated_pair(complete::u32, tag(","), complete::u32)(input)?;
+
+    Ok((input, Point(point.0 as usize, point.1 as usize)))
+}
+
+fn parse_lines(input: &str) -> IResult<&str, Vec<Line>> {
+    let (input, points) = separated_list1(tag(" -> "), parse_point)(input)?;
+
+    let lines = points
+        .iter()
+        .tuple_windows()
+        .map(|(l, r)| {
+            if l.0 == r.0 {
+                Line(Point(l.0, l.1.min(r.1)), Point(l.0, l.1.max(r.1)))
+            } else {
+                Line(Point(l.0.min(r.0), l.1), Point(l.0.max(r.0), l.1))
+            }
+        })
+        .collect::<Vec<Line>>();
+    Ok((input, lines))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
+    let (input, list) = separated_list1(line_ending, parse_lines)(input)?;
+    let list = list.into_iter().flatten().collect();
+
+    Ok((input, list))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    Sand,
+    Wall,
+}
+
+#[aoc_generator(day14)]
+pub fn day14_generator(input: &str) -> Input {
+    let (remainder, input) = parse_input(input).unwrap();
+    assert!(remainder.is_empty());
+    input
+}
+
+pub struct Grid<'a>(&'a Vec<Vec<Option<Tile>>>);
+
+impl<'a> Display for Grid<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{0}\n{2}\n{1}",
+            format_args!(
+                "{}{}{}",
+                "▛".bright_white().bold(),
+                ("▀".bright_white().bold()).repeat(self.0.first().map(|f| f.len()).unwrap_or(0)),
+                "▜".bright_white().bold()
+            ),
+            format_args!(
+                "{}{}{}",
+                "▙".bright_white().bold(),
+                ("▄".bright_white().bold()).repeat(self.0.first().map(|f| f.len()).unwrap_or(0)),
+                "▟".bright_white().bold()
+            ),
+            self.0
+                .iter()
+                .map(|l| format!(
+                    "{1}{}{2}",
+                    l.iter()
+                        .map(|p| match p {
+                            Some(Tile::Wall) => "█".white(),
+                            Some(Tile::Sand) => "ஃ".bright_yellow().bold(),
+                            None => " ".clear(),
+                        })
+                        .join(""),
+                    "▌".bright_white().bold(),
+                    "▐".bright_white().bold()
+                ))
+                .join("\n")
+        )?;
+        Ok(())
+    }
+}
+
+pub struct SandCave {
+    grid: Vec<Vec<Option<Tile>>>,
+    source: usize,
+    floor: bool,
+    resting: usize,
+    done: bool,
+}
+
+impl SandCave {
+    pub fn new(input: &Input, floor: bool) -> Self {
+        let max_y = input
+            .iter()
+            .flat_map(|line| [line.0 .1, line.1 .1])
+            .max()
+            .unwrap_or(0);
+
+        // Sand can spread at most one column per row below the source. Columns
+        // are shifted right by `reach` so the left edge stays positive however
+        // deep the cave goes, and sand never gets to the outermost columns.
+        let reach = max_y + 3;
+        let (left, right) = match input
+            .iter()
+            .flat_map(|line| [line.0 .0, line.1 .0])
+            .map(|x| x + reach)
+            .chain([500, 500 + 2 * reach])
+            .minmax()
+        {
+            MinMax(min, max) => (min, max),
+            _ => unreachable!(),
+        };
+
+        let mut grid: Vec<Vec<Option<Tile>>> = vec![vec![None; 1 + right - left]; max_y + 3];
+
+        for line in input.iter() {
+            for row in grid.iter_mut().take(line.1 .1 + 1).skip(line.0 .1) {
+                for x in line.0 .0..=line.1 .0 {
+                    row[x + reach - left] = Some(Tile::Wall);
+                }
+            }
+        }
+
+        if floor {
+            for point in grid[max_y + 2].iter_mut() {
+                *point = Some(Tile::Wall);
+            }
+        }
+
+        SandCave {
+            grid,
+            source: 500 + reach - left,
+            floor,
+            resting: 0,
+            done: false,
+        }
+    }
+
+    /// Rows of the cave from the top, the sand source is in column `source`
+    pub fn grid(&self) -> &[Vec<Option<Tile>>] {
+        &self.grid
+    }
+
+    pub fn source(&self) -> usize {
+        self.source
+    }
+}
+
+impl Simulation for SandCave {
+    type Snapshot<'a> = Grid<'a>;
+
+    fn step(&mut self) {
+        let mut point = (0, self.source);
+
+        loop {
+            if !self.floor && point.0 + 3 == self.grid.len() {
+                // Fell into the abyss
+                self.done = true;
+                return;
+            }
+
+            let below = &self.grid[point.0 + 1];
+            if below[point.1].is_none() {
+                point.0 += 1;
+            } else if below[point.1 - 1].is_none() {
+                point = (point.0 + 1, point.1 - 1);
+            } else if below[point.1 + 1].is_none() {
+                point = (point.0 + 1, point.1 + 1);
+            } else {
+                break;
+            }
+        }
+
+        self.grid[point.0][point.1] = Some(Tile::Sand);
+        self.resting += 1;
+
+        if point == (0, self.source) {
+            // Source is blocked
+            self.done = true;
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    fn snapshot(&self) -> Self::Snapshot<'_> {
+        Grid(&self.grid)
+    }
+}
+
+#[aoc(day14, part1)]
+pub fn solve_part1(input: &Input) -> usize {
+    let mut cave = SandCave::new(input, false);
+    cave.run();
+
+    println!("{}", cave.snapshot());
+
+    cave.resting
+}
+
+#[aoc(day14, part2)]
+pub fn solve_part2(input: &Input) -> usize {
+    let mut cave = SandCave::new(input, true);
+    cave.run();
+    cave.resting
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(&day14_generator(EXAMPLE)), 24);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(&day14_generator(EXAMPLE)), 93);
+    }
+
+    #[test]
+    fn deep_rocks_leave_room_on_the_left() {
+        // On the floor the sand spreads 601 columns left of x = 500, past x = 0
+        let input = day14_generator("500,600 -> 500,600");
+        assert_eq!(solve_part1(&input), 0);
+        assert_eq!(solve_part2(&input), 602 * 602 - 1);
+    }
+}
@@ -1,10 +1,13 @@
+use crate::arith::{self, OverflowError};
+use crate::config;
+use crate::explain::step;
+use crate::parse;
 use aoc_runner_derive::{aoc, aoc_generator};
 use itertools::Itertools;
 
 use nom::{
     bytes::complete::tag,
-    character::complete::{self, line_ending},
-    multi::separated_list1,
+    character::complete,
     sequence::{preceded, separated_pair},
     IResult,
 };
@@ -155,54 +158,64 @@ fn parse_sensor(input: &str) -> IResult<&str, Sensor> {
     ))
 }
 
-fn parse_input(input: &str) -> IResult<&str, Input> {
-    let (input, list) = separated_list1(line_ending, parse_sensor)(input)?;
-
-    Ok((input, list))
-}
-
 #[aoc_generator(day15)]
 pub fn day15_generator(input: &str) -> Input {
-    let (input, output) = parse_input(input).unwrap();
-    assert!(input.is_empty());
-    output
+    parse::all(parse::lines(parse_sensor), input)
 }
 
 #[aoc(day15, part1)]
 pub fn solve_part1(input: &Input) -> u64 {
+    let row = config::param(15, "row", 2_000_000);
     let mut overlap_gaps: Vec<(i64, i64)> = vec![];
 
     let mut gap_iter = input
         .iter()
-        .filter_map(|b| b.overlap_y(2_000_000))
+        .filter_map(|b| b.overlap_y(row))
         .sorted_unstable();
 
     let (mut start, mut end) = gap_iter.next().expect("No elements");
 
+    step!("row {row} starts with the span {start}..={end}");
     for (next_start, next_end) in gap_iter {
         if next_start <= end {
+            step!(
+                "{next_start}..={next_end} overlaps {start}..={end}, merged into {start}..={}",
+                end.max(next_end)
+            );
             end = end.max(next_end);
         } else {
+            step!("{next_start}..={next_end} is past {start}..={end}, which is done");
             overlap_gaps.push((start, end));
             start = next_start;
             end = next_end;
         }
     }
     overlap_gaps.push((start, end));
+    step!(
+        "covered: {}",
+        overlap_gaps
+            .iter()
+            .map(|(l, r)| format!("{l}..={r}"))
+            .join(", ")
+    );
 
     overlap_gaps.iter().map(|(l, r)| l.abs_diff(*r)).sum()
 }
 
 #[aoc(day15, part2)]
-pub fn solve_part2(input: &Input) -> i64 {
+pub fn solve_part2(input: &Input) -> Result<i64, OverflowError> {
+    let bound = config::param(15, "bound", 4_000_000);
     let Some((x, y)) = input
         .iter()
         .combinations(2)
         .flat_map(|compare| compare[0].intersection(compare[1]))
-        .filter(|&p| p.0 >= 0 && p.0 <= 4_000_000 && p.1 >= 0 && p.1 <= 4_000_000)
-        .find(|point| !input.iter().any(|sensor| sensor.point_in_range(*point))) else {
-            panic!("No Solution!");
-        };
-
-    x * 4_000_000 + y
+        .filter(|&p| p.0 >= 0 && p.0 <= bound && p.1 >= 0 && p.1 <= bound)
+        .find(|point| !input.iter().any(|sensor| sensor.point_in_range(*point)))
+    else {
+        panic!("No Solution!");
+    };
+
+    arith::mul(x, 4_000_000)
+        .and_then(|x| arith::add(x, y))
+        .map_err(|e| e.during(15, 2))
 }
@@ -0,0 +1,695 @@
+use crate::budget::{self, Cancelled};
+use crate::config;
+use crate::dot;
+use crate::explain::step;
+use crate::optimize::{self, Problem};
+use crate::search;
+use aoc_runner_derive::aoc;
+use itertools::Itertools;
+use petgraph::{algo::dijkstra, prelude::*};
+use std::collections::{HashMap, HashSet};
+
+use nom::{
+    bytes::complete::{tag, take},
+    character::complete::{self, line_ending},
+    combinator::opt,
+    multi::separated_list1,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+type Input<'a> = HashMap<&'a str, (u32, Vec<&'a str>)>;
+
+fn parse_line(input: &str) -> IResult<&str, (&str, (u32, Vec<&str>))> {
+    tuple((
+        preceded(tag("Valve "), take(2_usize)),
+        tuple((
+            preceded(tag(" has flow rate="), complete::u32),
+            preceded(
+                tuple((
+                    tag("; tunnel"),
+                    opt(tag("s")),
+                    tag(" lead"),
+                    opt(tag("s")),
+                    tag(" to valve"),
+                    opt(tag("s")),
+                    tag(" "),
+                )),
+                separated_list1(tag(", "), take(2_usize)),
+            ),
+        )),
+    ))(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Input<'_>> {
+    let (input, list) = separated_list1(line_ending, parse_line)(input)?;
+
+    Ok((input, list.into_iter().collect()))
+}
+
+pub fn day16_generator(input: &str) -> Input<'_> {
+    let _input = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+    let (input, output) = parse_input(input).unwrap();
+    assert!(input.is_empty());
+    output
+}
+
+type Distances<'a> = HashMap<(&'a str, &'a str), u32>;
+
+// Valves worth visiting: the start, and every valve with a positive flow rate
+fn valves<'a>(input: &Input<'a>) -> HashMap<&'a str, u32> {
+    input
+        .iter()
+        .filter_map(|(id, &(flow, _))| {
+            if id.eq(&"AA") || flow > 0 {
+                Some((*id, flow))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// Shortest paths between valves, walking the tunnels directly
+fn tunnel_distances<'a>(input: &Input<'a>, valves: &HashMap<&'a str, u32>) -> Distances<'a> {
+    valves
+        .keys()
+        .flat_map(|&node| {
+            let paths = search::bfs(node, |&from| input[from].1.iter().copied(), |_| false);
+
+            valves
+                .keys()
+                .filter_map(move |&to| match paths.distance(to) {
+                    Some(dist) if dist > 0 => Some(((node, to), dist as u32)),
+                    _ => None,
+                })
+        })
+        .collect()
+}
+
+// The tunnels as a petgraph graph, with zero-flow valves compressed out into longer edges
+fn compressed_tunnels<'a>(input: &Input<'a>) -> UnGraphMap<&'a str, u32> {
+    let mut graph = UnGraphMap::<&str, u32>::from_edges(
+        input
+            .iter()
+            .flat_map(|(from, (_flow, to))| to.iter().map(|t| (*from, *t, 1))),
+    );
+
+    let mut new_edges = vec![];
+
+    // Remove nodes with 0 flow
+    for (node, _) in input
+        .iter()
+        .filter(|&(id, (flow, _))| *flow == 0 && !id.eq(&"AA"))
+    {
+        let edges = graph.edges(node).collect_vec();
+        for (from, to, distance) in edges.iter().enumerate().flat_map(|(index, edge)| {
+            edges
+                .iter()
+                .skip(index + 1)
+                .map(|other| (edge.1, other.1, edge.2 + other.2))
+        }) {
+            new_edges.push((from, to, distance));
+        }
+
+        // Keep an existing tunnel when it is already shorter
+        for (from, to, distance) in new_edges.drain(..) {
+            let shortest = graph
+                .edge_weight(from, to)
+                .map_or(distance, |&known| known.min(distance));
+            graph.add_edge(from, to, shortest);
+        }
+
+        graph.remove_node(node);
+    }
+
+    graph
+}
+
+// Shortest paths between valves, after compressing zero-flow valves out of a petgraph graph
+fn petgraph_distances<'a>(input: &Input<'a>, valves: &HashMap<&'a str, u32>) -> Distances<'a> {
+    let graph = compressed_tunnels(input);
+
+    valves
+        .keys()
+        .flat_map(|node| {
+            dijkstra(&graph, node, None, |w| *w.weight())
+                .into_iter()
+                .filter(|&(_, dist)| dist > 0)
+                .map(move |(to, dist)| ((*node, to), dist))
+        })
+        .collect()
+}
+
+fn most_pressure(input: &HashMap<&str, u32>, shortest_paths: &Distances, minutes: u32) -> u32 {
+    // Node, minutes, flow_rate, elapsed_flow, open valves
+    let mut path = vec![(
+        "AA",
+        minutes,
+        0,
+        0,
+        ["AA"].into_iter().collect::<HashSet<&str>>(),
+    )];
+    let mut max = 0;
+
+    while let Some((curr, minutes, flow, elapsed, opened)) = path.pop() {
+        let mut at_end = true;
+
+        for (edge, distance, next_flow) in shortest_paths
+            .iter()
+            .filter(|((from, to), _)| from == &curr && !opened.contains(to))
+            .filter_map(|((_, edge), &distance)| {
+                if let Some(flow) = input.get(edge)
+                    && distance < minutes - 1
+                {
+                    Some((edge, distance, flow))
+                } else {
+                    None
+                }
+            })
+        {
+            let mut open_next = opened.clone();
+            open_next.insert(edge);
+            path.push((
+                edge,
+                // Travel + turn on
+                (minutes - distance) - 1,
+                flow + next_flow,
+                // NEED TO ACCOUNT FOR MINUTE TURNING IT ON
+                elapsed + (flow * (1 + distance)),
+                open_next,
+            ));
+            at_end = false;
+        }
+
+        if at_end {
+            // every valve is on?
+            let curr_max = elapsed + (minutes * flow);
+            if curr_max > max {
+                max = curr_max;
+            }
+        }
+    }
+
+    max
+}
+
+// Also says whether the search finished before the time budget ran out
+fn most_pressure_with_elephant(
+    input: &HashMap<&str, u32>,
+    shortest_paths: &Distances,
+    minutes: u32,
+) -> (u32, bool) {
+    // Node, minutes, flow_rate, elapsed_flow, open valves
+    let mut path = vec![(
+        "AA",
+        minutes,
+        0,
+        0,
+        ["AA"].into_iter().collect::<HashSet<&str>>(),
+    )];
+    let mut max = 0;
+    let mut max_set = HashSet::new();
+    let mut complete = true;
+
+    while let Some((curr, minutes, flow, elapsed, mut opened)) = path.pop() {
+        if budget::cancelled() {
+            complete = false;
+            break;
+        }
+
+        let mut at_end = true;
+        opened.insert(curr);
+
+        for (edge, distance, next_flow) in shortest_paths
+            .iter()
+            .filter(|((from, to), _)| from == &curr && !opened.contains(to))
+            .filter_map(|((_, edge), &distance)| {
+                if let Some(flow) = input.get(edge)
+                    && distance < minutes - 1
+                {
+                    Some((edge, distance, flow))
+                } else {
+                    None
+                }
+            })
+        {
+            path.push((
+                edge,
+                // Travel + turn on
+                (minutes - distance) - 1,
+                flow + next_flow,
+                // NEED TO ACCOUNT FOR MINUTE TURNING IT ON
+                elapsed + (flow * (1 + distance)),
+                opened.clone(),
+            ));
+            at_end = false;
+        }
+
+        if at_end {
+            // every valve is on?
+            let curr_max = elapsed + (minutes * flow);
+            if curr_max > max {
+                max = curr_max;
+                max_set = opened
+            }
+        }
+    }
+
+    // Node, minutes, flow_rate, elapsed_flow, open valves
+    path = vec![("AA", minutes, 0, 0, max_set)];
+    let mut elephant_max = 0;
+
+    while let Some((curr, minutes, flow, elapsed, mut opened)) = path.pop() {
+        if budget::cancelled() {
+            complete = false;
+            break;
+        }
+
+        let mut at_end = true;
+        opened.insert(curr);
+
+        for (edge, distance, next_flow) in shortest_paths
+            .iter()
+            .filter(|((from, to), _)| from == &curr && !opened.contains(to))
+            .filter_map(|((_, edge), &distance)| {
+                if let Some(flow) = input.get(edge)
+                    && distance < minutes - 1
+                {
+                    Some((edge, distance, flow))
+                } else {
+                    None
+                }
+            })
+        {
+            path.push((
+                edge,
+                // Travel + turn on
+                (minutes - distance) - 1,
+                flow + next_flow,
+                // NEED TO ACCOUNT FOR MINUTE TURNING IT ON
+                elapsed + (flow * (1 + distance)),
+                opened.clone(),
+            ));
+            at_end = false;
+        }
+
+        if at_end {
+            // every valve is on?
+            let curr_max = elapsed + (minutes * flow);
+            if curr_max > elephant_max {
+                elephant_max = curr_max;
+            }
+        }
+    }
+
+    (max + elephant_max, complete)
+}
+
+pub struct ValveTour<'a> {
+    names: Vec<&'a str>,
+    flows: Vec<u32>,
+    distances: Vec<Vec<u32>>,
+    // Valves by decreasing flow rate
+    by_flow: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Tour {
+    valve: usize,
+    minutes: u32,
+    opened: u64,
+    released: u32,
+}
+
+impl<'a> ValveTour<'a> {
+    pub fn new(input: &Input<'a>) -> Self {
+        let valves = valves(input);
+        let distances = tunnel_distances(input, &valves);
+
+        // AA sorts first, so it is always valve 0
+        let names = valves.keys().copied().sorted().collect_vec();
+        assert!(names.len() <= 64, "Too many valves to track");
+
+        ValveTour {
+            flows: names.iter().map(|name| valves[name]).collect(),
+            distances: names
+                .iter()
+                .map(|&from| {
+                    names
+                        .iter()
+                        .map(|&to| distances.get(&(from, to)).copied().unwrap_or(0))
+                        .collect()
+                })
+                .collect(),
+            by_flow: (0..names.len())
+                .sorted_by_key(|&v| std::cmp::Reverse(valves[names[v]]))
+                .collect(),
+            names,
+        }
+    }
+
+    pub fn start(&self, minutes: u32) -> Tour {
+        Tour {
+            valve: 0,
+            minutes,
+            opened: 0,
+            released: 0,
+        }
+    }
+
+    /// Valves opened in turn by an optimal tour, after starting at AA
+    pub fn best_route(&self, minutes: u32) -> Vec<&'a str> {
+        let start = self.start(minutes);
+        let best = optimize::best_first(self, start.clone()).value;
+
+        let mut route = vec![0];
+        self.route_to(&start, best, &mut route);
+        route.into_iter().map(|v| self.names[v]).collect()
+    }
+
+    // Follow branches that can still reach `best` until one releases it
+    fn route_to(&self, state: &Tour, best: u32, route: &mut Vec<usize>) -> bool {
+        if state.released == best {
+            return true;
+        }
+
+        for next in self.branches(state) {
+            if self.upper_bound(&next) >= best {
+                route.push(next.valve);
+                if self.route_to(&next, best, route) {
+                    return true;
+                }
+                route.pop();
+            }
+        }
+        false
+    }
+}
+
+impl<'a> Problem for ValveTour<'a> {
+    type State = Tour;
+    type Value = u32;
+    type Key = (usize, u64);
+
+    fn value(&self, state: &Tour) -> u32 {
+        state.released
+    }
+
+    fn upper_bound(&self, state: &Tour) -> u32 {
+        // Every further valve takes at least a minute to reach and a minute to open
+        state.released
+            + self
+                .by_flow
+                .iter()
+                .filter(|&&v| state.opened & (1 << v) == 0)
+                .zip((0..state.minutes.saturating_sub(1)).rev().step_by(2))
+                .map(|(&v, minutes)| self.flows[v] * minutes)
+                .sum::<u32>()
+    }
+
+    fn branches(&self, state: &Tour) -> Vec<Tour> {
+        (0..self.names.len())
+            .filter(|&v| self.flows[v] > 0 && state.opened & (1 << v) == 0)
+            .filter(|&v| self.distances[state.valve][v] + 1 < state.minutes)
+            .map(|v| {
+                let minutes = state.minutes - self.distances[state.valve][v] - 1;
+                Tour {
+                    valve: v,
+                    minutes,
+                    opened: state.opened | (1 << v),
+                    released: state.released + self.flows[v] * minutes,
+                }
+            })
+            .collect()
+    }
+
+    fn key(&self, state: &Tour) -> Option<(usize, u64)> {
+        Some((state.valve, state.opened))
+    }
+
+    fn dominates(&self, state: &Tour, other: &Tour) -> bool {
+        state.minutes >= other.minutes && state.released >= other.released
+    }
+}
+
+fn time_limit() -> u32 {
+    config::param(16, "minutes", 30)
+}
+
+fn time_limit_with_elephant() -> u32 {
+    config::param(16, "minutes_with_elephant", 26)
+}
+
+pub fn solve_part1(input: &Input) -> Result<u32, Cancelled> {
+    let tour = ValveTour::new(input);
+    let best = optimize::best_first(&tour, tour.start(time_limit()));
+
+    step!("{}", best.stats);
+
+    budget::outcome(16, 1, best.value, best.complete)
+}
+
+pub fn solve_part2(input: &Input) -> Result<u32, Cancelled> {
+    let valves = valves(input);
+    let (pressure, complete) = most_pressure_with_elephant(
+        &valves,
+        &tunnel_distances(input, &valves),
+        time_limit_with_elephant(),
+    );
+    budget::outcome(16, 2, pressure, complete)
+}
+
+pub fn solve_part1_exhaustive(input: &Input) -> u32 {
+    let valves = valves(input);
+    most_pressure(&valves, &tunnel_distances(input, &valves), time_limit())
+}
+
+pub fn solve_part1_petgraph(input: &Input) -> u32 {
+    let valves = valves(input);
+    most_pressure(&valves, &petgraph_distances(input, &valves), time_limit())
+}
+
+pub fn solve_part2_petgraph(input: &Input) -> Result<u32, Cancelled> {
+    let valves = valves(input);
+    let (pressure, complete) = most_pressure_with_elephant(
+        &valves,
+        &petgraph_distances(input, &valves),
+        time_limit_with_elephant(),
+    );
+    budget::outcome(16, 2, pressure, complete)
+}
+
+/// The tunnels with each valve's flow rate, and an optimal part 1 route highlighted
+///
+/// With `compressed` the zero-flow valves are replaced by longer tunnels, labelled with their length.
+pub fn tunnel_graph(input: &Input, compressed: bool) -> dot::Graph {
+    let mut tunnels = compressed_tunnels(input);
+    let route = ValveTour::new(input).best_route(time_limit());
+
+    // Drop tunnels some other way beats, so every length shown is one the solvers use
+    let exact = tunnel_distances(input, &valves(input));
+    let detours = tunnels
+        .all_edges()
+        .filter(|&(a, b, &length)| exact.get(&(a, b)) != Some(&length))
+        .map(|(a, b, _)| (a, b))
+        .collect_vec();
+    for (a, b) in detours {
+        tunnels.remove_edge(a, b);
+    }
+
+    // Tunnels walked along the route, each with its ends in order
+    let mut walked = HashSet::new();
+    for leg in route.windows(2) {
+        let path = if compressed {
+            search::dijkstra(
+                leg[0],
+                |&from| tunnels.edges(from).map(|(_, to, &length)| (to, length)),
+                |&valve| valve == leg[1],
+            )
+            .path_to(leg[1])
+        } else {
+            search::bfs(
+                leg[0],
+                |&from| input[from].1.iter().copied(),
+                |&valve| valve == leg[1],
+            )
+            .path_to(leg[1])
+        };
+        for step in path.unwrap_or_default().windows(2) {
+            walked.insert((step[0].min(step[1]), step[0].max(step[1])));
+        }
+    }
+
+    let edges = if compressed {
+        tunnels
+            .all_edges()
+            .map(|(a, b, &length)| (a.min(b), a.max(b), Some(length)))
+            .sorted()
+            .collect_vec()
+    } else {
+        input
+            .iter()
+            .flat_map(|(&from, (_, to))| {
+                to.iter().map(move |&to| (from.min(to), from.max(to), None))
+            })
+            .sorted()
+            .dedup()
+            .collect_vec()
+    };
+
+    let mut graph = dot::Graph::new("day 16", false);
+    let names = if compressed {
+        tunnels.nodes().sorted().collect_vec()
+    } else {
+        input.keys().copied().sorted().collect_vec()
+    };
+    for name in names {
+        let label = format!("{name}\nflow {}", input[name].0);
+        let mut attrs = vec![("label", label.as_str())];
+        if name == "AA" {
+            attrs.push(("shape", "doublecircle"));
+        }
+        if route.contains(&name) {
+            attrs.extend(dot::HIGHLIGHT);
+        }
+        graph.node(name, &attrs);
+    }
+    for (from, to, length) in edges {
+        let length = length.map(|l| l.to_string());
+        let mut attrs = vec![];
+        if let Some(length) = &length {
+            attrs.push(("label", length.as_str()));
+        }
+        if walked.contains(&(from, to)) {
+            attrs.extend(dot::HIGHLIGHT);
+        }
+        graph.edge(from, to, &attrs);
+    }
+
+    graph
+}
+
+// Parses as part of solving, see `runner::day!`
+#[aoc(day16, part1)]
+fn part1(input: &str) -> Result<u32, Cancelled> {
+    solve_part1(&day16_generator(input))
+}
+
+#[aoc(day16, part2)]
+fn part2(input: &str) -> Result<u32, Cancelled> {
+    solve_part2(&day16_generator(input))
+}
+
+#[aoc(day16, part1, Exhaustive)]
+fn part1_exhaustive(input: &str) -> u32 {
+    solve_part1_exhaustive(&day16_generator(input))
+}
+
+#[aoc(day16, part1, Petgraph)]
+fn part1_petgraph(input: &str) -> u32 {
+    solve_part1_petgraph(&day16_generator(input))
+}
+
+#[aoc(day16, part2, Petgraph)]
+fn part2_petgraph(input: &str) -> Result<u32, Cancelled> {
+    solve_part2_petgraph(&day16_generator(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    // The edges of a rendered graph as `from -- to` with any label
+    fn edges(graph: &dot::Graph) -> Vec<String> {
+        graph
+            .to_string()
+            .lines()
+            .filter(|line| line.contains(" -- "))
+            .map(|line| {
+                let edge = line.trim().split(" [").next().unwrap().replace('"', "");
+                match line.split("label=\"").nth(1) {
+                    Some(label) => format!("{edge} {}", label.split('"').next().unwrap()),
+                    None => edge,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = parse_input(EXAMPLE).unwrap().1;
+        assert_eq!(solve_part1(&input), Ok(1651));
+        assert_eq!(solve_part1_exhaustive(&input), 1651);
+        assert_eq!(solve_part1_petgraph(&input), 1651);
+    }
+
+    #[test]
+    fn compressed_tunnels_keep_the_shortest_way() {
+        let input = parse_input(EXAMPLE).unwrap().1;
+        let exact = tunnel_distances(&input, &valves(&input));
+        let graph = compressed_tunnels(&input);
+        for (a, b, &length) in graph.all_edges() {
+            assert!(
+                length >= exact[&(a, b)],
+                "{a} -- {b} is shorter than it can be"
+            );
+        }
+        assert_eq!(petgraph_distances(&input, &valves(&input)), exact);
+
+        assert_eq!(
+            edges(&tunnel_graph(&input, true)),
+            [
+                "AA -- BB 1",
+                "AA -- DD 1",
+                "AA -- JJ 2",
+                "BB -- CC 1",
+                "CC -- DD 1",
+                "DD -- EE 1",
+                "EE -- HH 3"
+            ]
+        );
+    }
+
+    #[test]
+    fn compressed_view_drops_detours() {
+        // AA reaches CC through XX and YY in 3, but through BB in 2
+        let input = parse_input(
+            "\
+Valve AA has flow rate=0; tunnels lead to valves BB, XX
+Valve BB has flow rate=5; tunnels lead to valves AA, CC
+Valve CC has flow rate=7; tunnels lead to valves BB, YY
+Valve XX has flow rate=0; tunnels lead to valves AA, YY
+Valve YY has flow rate=0; tunnels lead to valves XX, CC",
+        )
+        .unwrap()
+        .1;
+        assert_eq!(compressed_tunnels(&input).edge_weight("AA", "CC"), Some(&3));
+        assert_eq!(
+            edges(&tunnel_graph(&input, true)),
+            ["AA -- BB 1", "BB -- CC 1"]
+        );
+        assert_eq!(edges(&tunnel_graph(&input, false)).len(), 5);
+    }
+}
@@ -0,0 +1,710 @@
+use crate::config;
+use crate::explain::{self, step};
+use aoc_runner_derive::{aoc, aoc_generator};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The shapes of the classic game, in the order `CLASSIC` lists them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    /// The shape this one defeats
+    pub fn beats(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        }
+    }
+
+    /// The shape that defeats this one
+    pub fn loses_to(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+            Shape::Scissors => Shape::Rock,
+        }
+    }
+
+    /// How playing this shape against `them` goes
+    pub fn against(self, them: Shape) -> Outcome {
+        if self == them {
+            Outcome::Draw
+        } else if self.beats() == them {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /// The shape to play against `them` for `outcome`
+    pub fn for_outcome(them: Shape, outcome: Outcome) -> Shape {
+        match outcome {
+            Outcome::Loss => them.beats(),
+            Outcome::Draw => them,
+            Outcome::Win => them.loses_to(),
+        }
+    }
+
+    /// Where the shape is in a game parsed from `CLASSIC`
+    pub fn index(self) -> usize {
+        match self {
+            Shape::Rock => 0,
+            Shape::Paper => 1,
+            Shape::Scissors => 2,
+        }
+    }
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+}
+
+/// How to read the second column of the strategy guide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    /// The second column is the shape to play
+    Shape,
+    /// The second column is the outcome the round must have
+    Outcome,
+}
+
+/// The puzzle's game, as a table `Game::parse` reads
+///
+/// Each shape has a name, the opponent's letter for it, the second column's
+/// letter for it, its score and the shapes it beats. The `outcomes` line gives
+/// the second column's letters for a loss, draw and win, and `points` their scores.
+pub const CLASSIC: &str = "\
+rock     A X 1 scissors
+paper    B Y 2 rock
+scissors C Z 3 paper
+outcomes X Y Z
+points   0 3 6";
+
+/// Rock-Paper-Scissors-Lizard-Spock
+pub const SPOCK: &str = "\
+rock     A V 1 scissors lizard
+paper    B W 2 rock spock
+scissors C X 3 paper lizard
+lizard   D Y 4 spock paper
+spock    E Z 5 scissors rock
+outcomes X Y Z
+points   0 3 6";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    Malformed(String),
+    UnknownShape(String),
+    DuplicateLetter(char),
+    /// Games need an odd number of shapes for each to beat half of the others
+    EvenShapes(usize),
+    /// With fewer than three shapes some round cannot be won or lost
+    TooFewShapes(usize),
+    /// A shape that does not beat exactly half of the others, or a pair with no single winner
+    NotCyclic(String),
+    /// No game with this many shapes to pick in the config
+    UnknownGame(i64),
+}
+
+impl Display for GameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            GameError::Malformed(line) => write!(f, "malformed game table line {line:?}"),
+            GameError::UnknownShape(name) => write!(f, "unknown shape {name:?}"),
+            GameError::DuplicateLetter(letter) => write!(f, "letter {letter} is used twice"),
+            GameError::EvenShapes(n) => write!(f, "{n} shapes, a cyclic game needs an odd number"),
+            GameError::TooFewShapes(n) => {
+                write!(f, "{n} shapes, a game needs at least 3 to win or lose")
+            }
+            GameError::NotCyclic(name) => {
+                write!(f, "{name} must beat exactly half of the other shapes")
+            }
+            GameError::UnknownGame(shapes) => {
+                write!(f, "no game with {shapes} shapes, try 3 or 5")
+            }
+        }
+    }
+}
+
+impl Error for GameError {}
+
+/// A cyclic game where every shape beats half of the others and loses to the rest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    // Letters for each shape in the first and second columns
+    them: Vec<char>,
+    you: Vec<char>,
+    // Second column letters for a loss, draw and win
+    outcomes: [char; 3],
+    scores: Vec<u32>,
+    // Scores for each outcome of a round
+    loss: u32,
+    draw: u32,
+    win: u32,
+    // Shapes each shape beats
+    beats: Vec<Vec<bool>>,
+}
+
+fn letter(word: &str) -> Option<char> {
+    let mut chars = word.chars();
+    chars.next().filter(|_| chars.next().is_none())
+}
+
+impl Game {
+    pub fn parse(table: &str) -> Result<Self, GameError> {
+        let malformed = |line: &str| GameError::Malformed(line.to_string());
+
+        let mut rows = vec![];
+        let mut outcomes = None;
+        let mut points = None;
+        for line in table.lines().filter(|line| !line.trim().is_empty()) {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            match words[..] {
+                ["outcomes", loss, draw, win] => match [loss, draw, win].map(letter) {
+                    [Some(loss), Some(draw), Some(win)] => outcomes = Some([loss, draw, win]),
+                    _ => return Err(malformed(line)),
+                },
+                ["points", loss, draw, win] => match [loss, draw, win].map(|s| s.parse().ok()) {
+                    [Some(loss), Some(draw), Some(win)] => points = Some([loss, draw, win]),
+                    _ => return Err(malformed(line)),
+                },
+                [name, them, you, score, ref beats @ ..] => {
+                    let them = letter(them).ok_or_else(|| malformed(line))?;
+                    let you = letter(you).ok_or_else(|| malformed(line))?;
+                    let score = score.parse::<u32>().map_err(|_| malformed(line))?;
+                    rows.push((name, them, you, score, beats.to_vec()));
+                }
+                _ => return Err(malformed(line)),
+            }
+        }
+        let outcomes = outcomes.ok_or_else(|| malformed("outcomes"))?;
+        let points = points.ok_or_else(|| malformed("points"))?;
+
+        let names = rows.iter().map(|row| row.0.to_string()).collect::<Vec<_>>();
+        let index = |name: &str| {
+            names
+                .iter()
+                .position(|n| n == name)
+                .ok_or_else(|| GameError::UnknownShape(name.to_string()))
+        };
+        let mut beats = vec![vec![false; rows.len()]; rows.len()];
+        for (i, row) in rows.iter().enumerate() {
+            for &beaten in &row.4 {
+                beats[i][index(beaten)?] = true;
+            }
+        }
+
+        let game = Game {
+            them: rows.iter().map(|row| row.1).collect(),
+            you: rows.iter().map(|row| row.2).collect(),
+            outcomes,
+            scores: rows.iter().map(|row| row.3).collect(),
+            loss: points[0],
+            draw: points[1],
+            win: points[2],
+            beats,
+            names,
+        };
+        game.validate()?;
+        Ok(game)
+    }
+
+    fn validate(&self) -> Result<(), GameError> {
+        let n = self.names.len();
+        if n % 2 != 1 {
+            return Err(GameError::EvenShapes(n));
+        }
+        if n < 3 {
+            return Err(GameError::TooFewShapes(n));
+        }
+
+        for letters in [&self.them, &self.you] {
+            for (i, letter) in letters.iter().enumerate() {
+                if letters[..i].contains(letter) {
+                    return Err(GameError::DuplicateLetter(*letter));
+                }
+            }
+        }
+        for (i, letter) in self.outcomes.iter().enumerate() {
+            if self.outcomes[..i].contains(letter) {
+                return Err(GameError::DuplicateLetter(*letter));
+            }
+        }
+
+        for i in 0..n {
+            let wins = (0..n).filter(|&j| self.beats[i][j]).count();
+            let one_winner = (0..n).all(|j| i == j || self.beats[i][j] != self.beats[j][i]);
+            if self.beats[i][i] || wins != n / 2 || !one_winner {
+                return Err(GameError::NotCyclic(self.names[i].clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// The game with as many shapes as the `shapes` parameter says, three by default,
+    /// with any shape or outcome scores overridden in the config
+    pub fn configured() -> Result<Self, GameError> {
+        let table = match config::param(2, "shapes", 3) {
+            3 => CLASSIC,
+            5 => SPOCK,
+            shapes => return Err(GameError::UnknownGame(shapes)),
+        };
+
+        let mut game = Game::parse(table)?;
+        for (name, score) in game.names.iter().zip(&mut game.scores) {
+            *score = config::param(2, name, *score);
+        }
+        game.loss = config::param(2, "loss", game.loss);
+        game.draw = config::param(2, "draw", game.draw);
+        game.win = config::param(2, "win", game.win);
+        Ok(game)
+    }
+
+    pub fn shapes(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Whether shape `a` defeats shape `b`
+    pub fn beats(&self, a: usize, b: usize) -> bool {
+        self.beats[a][b]
+    }
+
+    /// The shapes that defeat `shape`
+    pub fn loses_to(&self, shape: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.names.len()).filter(move |&other| self.beats[other][shape])
+    }
+
+    /// How playing shape `you` against shape `them` goes
+    pub fn outcome(&self, you: usize, them: usize) -> Outcome {
+        if you == them {
+            Outcome::Draw
+        } else if self.beats(you, them) {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /// The shape to play against `them` for `outcome`
+    ///
+    /// With more than three shapes several will do, so the best scoring one is played.
+    pub fn for_outcome(&self, them: usize, outcome: Outcome) -> usize {
+        (0..self.names.len())
+            .filter(|&you| self.outcome(you, them) == outcome)
+            .min_by_key(|&you| std::cmp::Reverse(self.scores[you]))
+            .expect("validated games have a shape for every outcome")
+    }
+
+    /// Points for a round ending in `outcome`
+    pub fn points(&self, outcome: Outcome) -> u32 {
+        match outcome {
+            Outcome::Loss => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+
+    pub fn score(&self, you: usize, them: usize) -> u32 {
+        self.scores[you] + self.points(self.outcome(you, them))
+    }
+
+    /// The shape the second column says to play against shape `them`
+    pub fn respond(&self, them: usize, column: char, reading: Reading) -> Option<usize> {
+        match reading {
+            Reading::Shape => self.you.iter().position(|&l| l == column),
+            Reading::Outcome => {
+                let outcome = self.outcomes.iter().position(|&l| l == column)?;
+                Some(self.for_outcome(them, Outcome::ALL[outcome]))
+            }
+        }
+    }
+
+    /// The shapes a round of the guide has both players show
+    pub fn play(&self, (them, column): (char, char), reading: Reading) -> Option<(usize, usize)> {
+        let them = self.them.iter().position(|&l| l == them)?;
+        Some((self.respond(them, column, reading)?, them))
+    }
+
+    /// Total score from following the guide read as `reading`
+    pub fn total(&self, input: &Input, reading: Reading) -> Result<u32, GuideError> {
+        input
+            .iter()
+            .enumerate()
+            .map(|(i, &round)| match self.play(round, reading) {
+                Some((you, them)) => Ok(self.score(you, them)),
+                None => Err(GuideError {
+                    line: i + 1,
+                    text: format!("{} {}", round.0, round.1),
+                }),
+            })
+            .sum()
+    }
+}
+
+/// A round of the guide that is not two letters, or uses letters the game does not have
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuideError {
+    pub line: usize,
+    pub text: String,
+}
+
+impl Display for GuideError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "line {}: not a round of the game: {:?}",
+            self.line, self.text
+        )
+    }
+}
+
+impl Error for GuideError {}
+
+/// Why the guide could not be followed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StrategyError {
+    Game(GameError),
+    Guide(GuideError),
+    /// A simulation asked to play the guide no times
+    NoTrials,
+}
+
+impl Display for StrategyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            StrategyError::Game(e) => write!(f, "{e}"),
+            StrategyError::Guide(e) => write!(f, "{e}"),
+            StrategyError::NoTrials => write!(f, "trials must be at least 1"),
+        }
+    }
+}
+
+impl Error for StrategyError {}
+
+impl From<GameError> for StrategyError {
+    fn from(e: GameError) -> Self {
+        StrategyError::Game(e)
+    }
+}
+
+impl From<GuideError> for StrategyError {
+    fn from(e: GuideError) -> Self {
+        StrategyError::Guide(e)
+    }
+}
+
+// The letters of each round, read according to a game
+type Input = Vec<(char, char)>;
+
+pub fn parse_guide(input: &str) -> Result<Input, GuideError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let round = line
+                .split_once(' ')
+                .and_then(|(them, you)| letter(them).zip(letter(you)));
+            round.ok_or_else(|| GuideError {
+                line: i + 1,
+                text: line.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[aoc_generator(day2)]
+pub fn day2_generator(input: &str) -> Input {
+    parse_guide(input).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// How many times `simulate` should play the guide, see the `trials` parameter
+pub fn trials() -> usize {
+    config::param(2, "trials", 100)
+}
+
+/// The seed of the simulated opponent, see the `seed` parameter
+pub fn seed() -> u64 {
+    config::param(2, "seed", 2022)
+}
+
+// How the guide fares against a random opponent, for `run --explain`
+fn explain_luck(game: &Game, input: &Input, reading: Reading) -> Result<(), StrategyError> {
+    if explain::enabled() {
+        let seed = seed();
+        let luck = simulate(game, input, reading, trials(), seed)?;
+        step!("against a random opponent (seed {seed}): {luck}");
+    }
+    Ok(())
+}
+
+#[aoc(day2, part1)]
+pub fn solve_part1(input: &Input) -> Result<u32, StrategyError> {
+    let game = Game::configured()?;
+    if explain::enabled() {
+        let mappings = rank_mappings(&game, input)?;
+        step!("best reading of the second column: {}", mappings[0]);
+        step!("worst reading: {}", mappings[mappings.len() - 1]);
+    }
+    explain_luck(&game, input, Reading::Shape)?;
+
+    Ok(game.total(input, Reading::Shape)?)
+}
+
+#[aoc(day2, part2)]
+pub fn solve_part2(input: &Input) -> Result<u32, StrategyError> {
+    let game = Game::configured()?;
+    explain_luck(&game, input, Reading::Outcome)?;
+
+    Ok(game.total(input, Reading::Outcome)?)
+}
+
+/// One way of reading the second column's letters, and the score the guide gets with it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    pub reading: Reading,
+    pub letters: Vec<(char, String)>,
+    pub total: u32,
+}
+
+impl Display for Mapping {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let letters = self
+            .letters
+            .iter()
+            .map(|(letter, meaning)| format!("{letter}={meaning}"))
+            .join(", ");
+        write!(f, "{letters}: {}", self.total)
+    }
+}
+
+/// Every bijection of the second column's letters onto shapes and onto
+/// outcomes, best scoring first
+pub fn rank_mappings(game: &Game, input: &Input) -> Result<Vec<Mapping>, GuideError> {
+    let mut mappings = vec![];
+
+    for you in game.you.iter().copied().permutations(game.you.len()) {
+        let mapped = Game {
+            you: you.clone(),
+            ..game.clone()
+        };
+        mappings.push(Mapping {
+            reading: Reading::Shape,
+            letters: you.into_iter().zip(game.names.iter().cloned()).collect(),
+            total: mapped.total(input, Reading::Shape)?,
+        });
+    }
+
+    for outcomes in game.outcomes.iter().copied().permutations(3) {
+        let mapped = Game {
+            outcomes: [outcomes[0], outcomes[1], outcomes[2]],
+            ..game.clone()
+        };
+        let meanings = ["loss", "draw", "win"].map(String::from);
+        mappings.push(Mapping {
+            reading: Reading::Outcome,
+            letters: outcomes.into_iter().zip(meanings).collect(),
+            total: mapped.total(input, Reading::Outcome)?,
+        });
+    }
+
+    mappings.sort_by_key(|m| std::cmp::Reverse(m.total));
+    Ok(mappings)
+}
+
+/// Scores from many plays of the guide, lowest first
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    pub scores: Vec<u32>,
+}
+
+impl Distribution {
+    pub fn mean(&self) -> f64 {
+        self.scores.iter().map(|&s| s as f64).sum::<f64>() / self.scores.len() as f64
+    }
+
+    /// The score `fraction` of the way up, 0.5 being the median
+    pub fn quantile(&self, fraction: f64) -> u32 {
+        let last = self.scores.len() - 1;
+        self.scores[(fraction.clamp(0.0, 1.0) * last as f64).round() as usize]
+    }
+}
+
+impl Display for Distribution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "{} plays: min {}, median {}, max {}, mean {:.1}",
+            self.scores.len(),
+            self.quantile(0.0),
+            self.quantile(0.5),
+            self.quantile(1.0),
+            self.mean()
+        )
+    }
+}
+
+/// Follow the guide's second column `trials` times against an opponent
+/// choosing shapes uniformly at random, the same ones for the same `seed`
+pub fn simulate(
+    game: &Game,
+    input: &Input,
+    reading: Reading,
+    trials: usize,
+    seed: u64,
+) -> Result<Distribution, StrategyError> {
+    if trials == 0 {
+        return Err(StrategyError::NoTrials);
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut scores = vec![];
+    for _ in 0..trials {
+        let mut total = 0;
+        for (i, &(_, column)) in input.iter().enumerate() {
+            let them = rng.gen_range(0..game.names.len());
+            let you = game
+                .respond(them, column, reading)
+                .ok_or_else(|| GuideError {
+                    line: i + 1,
+                    text: column.to_string(),
+                })?;
+            total += game.score(you, them);
+        }
+        scores.push(total);
+    }
+
+    scores.sort_unstable();
+    Ok(Distribution { scores })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+A Y
+B X
+C Z";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(solve_part1(&day2_generator(EXAMPLE)), Ok(15));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(solve_part2(&day2_generator(EXAMPLE)), Ok(12));
+    }
+
+    #[test]
+    fn shapes_match_the_classic_table() {
+        let game = Game::parse(CLASSIC).unwrap();
+        for you in Shape::ALL {
+            assert_eq!(
+                game.shapes()[you.index()],
+                format!("{you:?}").to_lowercase()
+            );
+            let losers = game.loses_to(you.beats().index()).collect::<Vec<_>>();
+            assert_eq!(losers, [you.index()]);
+            assert_eq!(
+                game.loses_to(you.index()).collect::<Vec<_>>(),
+                [you.loses_to().index()]
+            );
+
+            for them in Shape::ALL {
+                assert_eq!(game.beats(you.index(), them.index()), you.beats() == them);
+                assert_eq!(game.outcome(you.index(), them.index()), you.against(them));
+            }
+            for outcome in Outcome::ALL {
+                let shape = Shape::for_outcome(you, outcome);
+                assert_eq!(game.for_outcome(you.index(), outcome), shape.index());
+            }
+        }
+    }
+
+    #[test]
+    fn spock_game() {
+        let game = Game::parse(SPOCK).unwrap();
+        let guide = day2_generator(EXAMPLE);
+        assert_eq!(game.total(&guide, Reading::Shape), Ok(24));
+        assert_eq!(game.total(&guide, Reading::Outcome), Ok(20));
+        assert_eq!(game.loses_to(0).count(), 2);
+    }
+
+    #[test]
+    fn games_need_a_win_and_a_loss_for_every_shape() {
+        let lonely = "rock A X 1\noutcomes X Y Z\npoints 0 3 6";
+        assert_eq!(Game::parse(lonely), Err(GameError::TooFewShapes(1)));
+        let even = "rock A X 1 paper\npaper B Y 2\noutcomes X Y Z\npoints 0 3 6";
+        assert_eq!(Game::parse(even), Err(GameError::EvenShapes(2)));
+        let unfair = CLASSIC.replace("scissors C Z 3 paper", "scissors C Z 3");
+        assert!(matches!(Game::parse(&unfair), Err(GameError::NotCyclic(_))));
+    }
+
+    #[test]
+    fn outcome_points_follow_the_table() {
+        let game = Game::parse(&CLASSIC.replace("points   0 3 6", "points 7 0 1")).unwrap();
+        assert_eq!(game.points(Outcome::Loss), 7);
+        assert_eq!(game.points(Outcome::Draw), 0);
+        assert_eq!(game.points(Outcome::Win), 1);
+    }
+
+    #[test]
+    fn malformed_rounds() {
+        let error = parse_guide("A Y\nBX\nC Z").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.text, "BX");
+
+        let game = Game::parse(CLASSIC).unwrap();
+        let error = game.total(&day2_generator("A Y\nD X"), Reading::Shape);
+        assert_eq!(error.unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn mappings_rank_every_reading() {
+        let game = Game::parse(CLASSIC).unwrap();
+        let mappings = rank_mappings(&game, &day2_generator(EXAMPLE)).unwrap();
+        assert_eq!(mappings.len(), 12);
+        assert_eq!(mappings[0].to_string(), "Z=rock, Y=paper, X=scissors: 24");
+        assert_eq!(mappings[11].to_string(), "X=rock, Z=paper, Y=scissors: 6");
+        assert!(mappings.windows(2).all(|w| w[0].total >= w[1].total));
+    }
+
+    #[test]
+    fn simulations_repeat_for_a_seed() {
+        let game = Game::parse(CLASSIC).unwrap();
+        let guide = day2_generator(EXAMPLE);
+        let luck = simulate(&game, &guide, Reading::Outcome, 50, 7).unwrap();
+        assert_eq!(luck.scores.len(), 50);
+        assert_eq!(
+            Ok(&luck),
+            simulate(&game, &guide, Reading::Outcome, 50, 7).as_ref()
+        );
+        // Three rounds, each scoring from 1 to 9
+        assert!(luck.quantile(0.0) >= 3 && luck.quantile(1.0) <= 27);
+        assert!(luck.scores.windows(2).all(|w| w[0] <= w[1]));
+
+        assert_eq!(
+            simulate(&game, &guide, Reading::Shape, 0, 7),
+            Err(StrategyError::NoTrials)
+        );
+    }
+}
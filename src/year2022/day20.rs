@@ -0,0 +1,122 @@
+use crate::arith::{self, Overflow, OverflowError};
+use crate::config;
+use crate::parse;
+use crate::simulation::Simulation;
+use aoc_runner_derive::{aoc, aoc_generator};
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+
+use nom::{character::complete, IResult};
+
+type Input = Vec<i64>;
+
+fn parse_input(input: &str) -> IResult<&str, Input> {
+    parse::lines(complete::i64)(input)
+}
+
+#[aoc_generator(day20)]
+pub fn day20_generator(input: &str) -> Input {
+    let _input = "1
+2
+-3
+3
+-2
+0
+4";
+    parse::all(parse_input, input)
+}
+
+pub struct Mixer {
+    // (original position, value) in mixed order
+    list: Vec<(usize, i64)>,
+    moves: usize,
+    rounds: usize,
+}
+
+impl Mixer {
+    pub fn new(input: &Input, key: i64, rounds: usize) -> Result<Self, Overflow> {
+        Ok(Mixer {
+            list: input
+                .iter()
+                .map(|&v| arith::mul(v, key))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .enumerate()
+                .collect(),
+            moves: 0,
+            rounds,
+        })
+    }
+
+    pub fn grove_coordinates(&self) -> i64 {
+        let zero_index = self.list.iter().position(|v| v.1 == 0).unwrap();
+        let a = self.list[(1000 + zero_index) % self.list.len()].1;
+        let b = self.list[(2000 + zero_index) % self.list.len()].1;
+        let c = self.list[(3000 + zero_index) % self.list.len()].1;
+
+        a + b + c
+    }
+}
+
+pub struct Mixed<'a>(&'a Mixer);
+
+impl<'a> Display for Mixed<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let mixer = self.0;
+        let len = mixer.list.len().max(1);
+        writeln!(
+            f,
+            "round: {}, moved: {}/{}",
+            mixer.moves / len,
+            mixer.moves % len,
+            len
+        )?;
+        write!(f, "{}", mixer.list.iter().map(|v| v.1).join(", "))
+    }
+}
+
+impl Simulation for Mixer {
+    type Snapshot<'a> = Mixed<'a>;
+
+    fn step(&mut self) {
+        let id = self.moves % self.list.len();
+        let index = self
+            .list
+            .iter()
+            .position(|output_value| output_value.0 == id)
+            .unwrap();
+
+        let current = self.list.remove(index);
+        let added = index as i64 + current.1;
+        let new_index = added.rem_euclid(self.list.len() as i64);
+
+        self.list.insert(new_index as usize, current);
+        self.moves += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.moves >= self.rounds * self.list.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot<'_> {
+        Mixed(self)
+    }
+}
+
+pub fn decryption_key() -> i64 {
+    config::param(20, "key", 811_589_153)
+}
+
+#[aoc(day20, part1)]
+pub fn solve_part1(input: &Input) -> Result<i64, OverflowError> {
+    let mut mixer = Mixer::new(input, 1, 1).map_err(|e| e.during(20, 1))?;
+    mixer.run();
+    Ok(mixer.grove_coordinates())
+}
+
+#[aoc(day20, part2)]
+pub fn solve_part2(input: &Input) -> Result<i64, OverflowError> {
+    let mut mixer = Mixer::new(input, decryption_key(), 10).map_err(|e| e.during(20, 2))?;
+    mixer.run();
+    Ok(mixer.grove_coordinates())
+}
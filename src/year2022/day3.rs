@@ -0,0 +1,390 @@
+use crate::budget;
+use crate::config;
+use crate::explain::step;
+use aoc_runner_derive::aoc;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+type Input<'a> = Vec<&'a [u8]>;
+
+pub fn day3_generator(input: &str) -> Input<'_> {
+    input.lines().map(str::as_bytes).collect()
+}
+
+// Priority of every byte, 0 for bytes that are not items
+const PRIORITIES: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 26 {
+        table[b'a' as usize + i] = i as u8 + 1;
+        table[b'A' as usize + i] = i as u8 + 27;
+        i += 1;
+    }
+    table
+};
+
+/// A set of item types, one bit per priority
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Items(u64);
+
+impl Items {
+    /// The items in `bytes`, or the first byte that is not an item
+    pub fn parse(bytes: &[u8]) -> Result<Self, u8> {
+        bytes
+            .iter()
+            .try_fold(Items(0), |items, &b| match PRIORITIES[b as usize] {
+                0 => Err(b),
+                p => Ok(Items(items.0 | 1 << p)),
+            })
+    }
+
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |p| self.0 & 1 << p != 0)
+    }
+}
+
+impl Display for Items {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for p in self.priorities() {
+            let item = match p {
+                1..=26 => b'a' + p as u8 - 1,
+                _ => b'A' + p as u8 - 27,
+            };
+            write!(f, "{}", item as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// Rucksacks that break the puzzle's rules, counting lines from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RucksackError {
+    OddLength {
+        line: usize,
+    },
+    InvalidItem {
+        line: usize,
+        item: char,
+    },
+    /// The compartments of a rucksack share no item, or several
+    Duplicates {
+        line: usize,
+        shared: Items,
+    },
+    /// A group shares no item, or several
+    Badges {
+        first_line: usize,
+        shared: Items,
+    },
+    /// No way to group the rucksacks so each group shares one item, or none found in time
+    NoPartition {
+        group_size: usize,
+        proven: bool,
+    },
+    /// The rucksacks do not split evenly into groups
+    IncompleteGroup {
+        rucksacks: usize,
+        group_size: usize,
+    },
+}
+
+impl Display for RucksackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            RucksackError::OddLength { line } => {
+                write!(
+                    f,
+                    "line {line}: odd number of items, compartments must match"
+                )
+            }
+            RucksackError::InvalidItem { line, item } => {
+                write!(f, "line {line}: {item:?} is not an item")
+            }
+            RucksackError::Duplicates { line, shared } if shared.is_empty() => {
+                write!(f, "line {line}: no item in both compartments")
+            }
+            RucksackError::Duplicates { line, shared } => {
+                write!(
+                    f,
+                    "line {line}: several items in both compartments ({shared})"
+                )
+            }
+            RucksackError::Badges { first_line, shared } if shared.is_empty() => {
+                write!(f, "group from line {first_line}: no badge")
+            }
+            RucksackError::Badges { first_line, shared } => {
+                write!(f, "group from line {first_line}: several badges ({shared})")
+            }
+            RucksackError::NoPartition {
+                group_size,
+                proven: true,
+            } => write!(
+                f,
+                "no grouping into {group_size}s with one badge each exists"
+            ),
+            RucksackError::NoPartition { group_size, .. } => write!(
+                f,
+                "no grouping into {group_size}s with one badge each found in time"
+            ),
+            RucksackError::IncompleteGroup {
+                rucksacks,
+                group_size,
+            } => write!(
+                f,
+                "{rucksacks} rucksacks do not form groups of {group_size}"
+            ),
+        }
+    }
+}
+
+impl Error for RucksackError {}
+
+/// The items in each compartment of the rucksack on `line`
+pub fn compartments(line: usize, rucksack: &[u8]) -> Result<(Items, Items), RucksackError> {
+    if rucksack.len() % 2 == 1 {
+        return Err(RucksackError::OddLength { line });
+    }
+
+    let (left, right) = rucksack.split_at(rucksack.len() / 2);
+    let parse = |items| {
+        Items::parse(items).map_err(|item| RucksackError::InvalidItem {
+            line,
+            item: item as char,
+        })
+    };
+    Ok((parse(left)?, parse(right)?))
+}
+
+// The priority of the only item in `shared`
+fn single(shared: Items, error: impl FnOnce(Items) -> RucksackError) -> Result<u32, RucksackError> {
+    match shared.len() {
+        1 => Ok(shared.priorities().next().unwrap()),
+        _ => Err(error(shared)),
+    }
+}
+
+pub fn group_size() -> usize {
+    config::param(3, "group_size", 3)
+}
+
+pub fn solve_part1(input: &Input) -> Result<u32, RucksackError> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, rucksack)| {
+            let (left, right) = compartments(i + 1, rucksack)?;
+            single(left.intersection(right), |shared| {
+                RucksackError::Duplicates {
+                    line: i + 1,
+                    shared,
+                }
+            })
+        })
+        .sum()
+}
+
+/// The items in every rucksack
+pub fn contents(input: &Input) -> Result<Vec<Items>, RucksackError> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, rucksack)| {
+            let (left, right) = compartments(i + 1, rucksack)?;
+            Ok(left.union(right))
+        })
+        .collect()
+}
+
+/// A grouping of rucksacks by index, or why there is none
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Partition {
+    Groups(Vec<Vec<usize>>),
+    Impossible,
+    /// The time budget ran out before the search finished
+    Abandoned,
+}
+
+struct Discovery<'a> {
+    contents: &'a [Items],
+    size: usize,
+    used: Vec<u64>,
+    groups: Vec<Vec<usize>>,
+    // Sets of used rucksacks that leave no way to group the rest
+    dead_ends: HashSet<Vec<u64>>,
+}
+
+impl<'a> Discovery<'a> {
+    fn is_used(&self, i: usize) -> bool {
+        self.used[i / 64] & 1 << (i % 64) != 0
+    }
+
+    fn toggle(&mut self, i: usize) {
+        self.used[i / 64] ^= 1 << (i % 64);
+    }
+
+    // Whether the unused rucksacks can all be grouped, or `None` once out of time
+    fn partition(&mut self) -> Option<bool> {
+        if budget::cancelled() {
+            return None;
+        }
+        let Some(first) = (0..self.contents.len()).find(|&i| !self.is_used(i)) else {
+            return Some(true);
+        };
+        if self.dead_ends.contains(&self.used) {
+            return Some(false);
+        }
+
+        // The first unused rucksack has to go somewhere, so only its groups need trying
+        self.toggle(first);
+        let found = self.complete(&mut vec![first], self.contents[first], first + 1)?;
+        self.toggle(first);
+        if !found {
+            self.dead_ends.insert(self.used.clone());
+        }
+        Some(found)
+    }
+
+    // Fill `group` with rucksacks from `next` on that keep a shared item, then group the rest
+    fn complete(&mut self, group: &mut Vec<usize>, shared: Items, next: usize) -> Option<bool> {
+        if group.len() == self.size {
+            if shared.len() != 1 {
+                return Some(false);
+            }
+            self.groups.push(group.clone());
+            if self.partition()? {
+                return Some(true);
+            }
+            self.groups.pop();
+            return Some(false);
+        }
+
+        for i in next..self.contents.len() {
+            let with = shared.intersection(self.contents[i]);
+            if self.is_used(i) || with.is_empty() {
+                continue;
+            }
+
+            self.toggle(i);
+            group.push(i);
+            let found = self.complete(group, with, i + 1)?;
+            group.pop();
+            self.toggle(i);
+            if found {
+                return Some(true);
+            }
+        }
+        Some(false)
+    }
+}
+
+/// Split the rucksacks into groups of `size` that each share exactly one item,
+/// when the order of the input says nothing about who is grouped with whom
+pub fn discover_groups(contents: &[Items], size: usize) -> Partition {
+    assert!(size > 0, "Groups must have at least one rucksack");
+    if !contents.chunks_exact(size).remainder().is_empty() {
+        return Partition::Impossible;
+    }
+
+    let mut discovery = Discovery {
+        contents,
+        size,
+        used: vec![0; contents.len() / 64 + 1],
+        groups: vec![],
+        dead_ends: HashSet::new(),
+    };
+    match discovery.partition() {
+        Some(true) => Partition::Groups(discovery.groups),
+        Some(false) => Partition::Impossible,
+        None => Partition::Abandoned,
+    }
+}
+
+// Part 2 for shuffled input, where the groups have to be worked out
+fn discovered_badges(input: &Input, size: usize) -> Result<u32, RucksackError> {
+    let contents = contents(input)?;
+    let groups = match discover_groups(&contents, size) {
+        Partition::Groups(groups) => groups,
+        Partition::Impossible => {
+            return Err(RucksackError::NoPartition {
+                group_size: size,
+                proven: true,
+            })
+        }
+        Partition::Abandoned => {
+            return Err(RucksackError::NoPartition {
+                group_size: size,
+                proven: false,
+            })
+        }
+    };
+
+    Ok(groups
+        .iter()
+        .map(|group| {
+            let shared = group.iter().fold(Items(u64::MAX), |shared, &i| {
+                shared.intersection(contents[i])
+            });
+            let lines = group
+                .iter()
+                .map(|i| (i + 1).to_string())
+                .collect::<Vec<_>>();
+            step!("lines {} share {shared}", lines.join(", "));
+            shared.priorities().next().unwrap()
+        })
+        .sum())
+}
+
+pub fn solve_part2(input: &Input) -> Result<u32, RucksackError> {
+    let size = group_size();
+    if size == 0 || !input.chunks_exact(size).remainder().is_empty() {
+        return Err(RucksackError::IncompleteGroup {
+            rucksacks: input.len(),
+            group_size: size,
+        });
+    }
+    if config::param(3, "find_groups", 0) != 0 {
+        return discovered_badges(input, size);
+    }
+
+    contents(input)?
+        .chunks(size)
+        .enumerate()
+        .map(|(group, rucksacks)| {
+            let shared = rucksacks
+                .iter()
+                .fold(Items(u64::MAX), |shared, &items| shared.intersection(items));
+            single(shared, |shared| RucksackError::Badges {
+                first_line: group * size + 1,
+                shared,
+            })
+        })
+        .sum()
+}
+
+// Parses as part of solving, see `runner::day!`
+#[aoc(day3, part1)]
+fn part1(input: &str) -> Result<u32, RucksackError> {
+    solve_part1(&day3_generator(input))
+}
+
+#[aoc(day3, part2)]
+fn part2(input: &str) -> Result<u32, RucksackError> {
+    solve_part2(&day3_generator(input))
+}
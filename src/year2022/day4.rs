@@ -0,0 +1,229 @@
+use crate::config;
+use crate::explain::{self, step};
+use aoc_runner_derive::{aoc, aoc_generator};
+use std::collections::BTreeMap;
+
+type Input = Vec<((u32, u32), (u32, u32))>;
+
+#[aoc_generator(day4)]
+pub fn day4_generator(input: &str) -> Input {
+    input
+        .lines()
+        .map(|line| {
+            let (left, right) = line.split_once(',').unwrap();
+            let (one, two) = left.split_once('-').unwrap();
+            let (three, four) = right.split_once('-').unwrap();
+
+            (
+                (one.parse().unwrap(), two.parse().unwrap()),
+                (three.parse().unwrap(), four.parse().unwrap()),
+            )
+        })
+        .collect()
+}
+
+/// How two elves' section assignments relate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Relation {
+    /// A gap of at least one section between them
+    Disjoint,
+    /// No shared sections, but one ends right before the other starts
+    Adjacent,
+    /// Some sections shared, and each has sections the other lacks
+    Overlapping,
+    /// Every section of one is in the other, which has more
+    Containing,
+    Equal,
+}
+
+impl Relation {
+    pub fn of((left, right): ((u32, u32), (u32, u32))) -> Self {
+        let (first, second) = if left <= right {
+            (left, right)
+        } else {
+            (right, left)
+        };
+
+        if left == right {
+            Relation::Equal
+        } else if first.0 == second.0 || first.1 >= second.1 {
+            // Sharing a start, the longer one contains the shorter
+            Relation::Containing
+        } else if first.1 >= second.0 {
+            Relation::Overlapping
+        } else if first.1 + 1 == second.0 {
+            Relation::Adjacent
+        } else {
+            Relation::Disjoint
+        }
+    }
+
+    /// True if either assignment fully contains the other
+    pub fn contains(self) -> bool {
+        matches!(self, Relation::Containing | Relation::Equal)
+    }
+
+    /// True if the assignments share any section
+    pub fn overlaps(self) -> bool {
+        matches!(
+            self,
+            Relation::Overlapping | Relation::Containing | Relation::Equal
+        )
+    }
+}
+
+/// How many pairs fall into each relation
+pub fn census(input: &Input) -> BTreeMap<Relation, usize> {
+    let mut counts = BTreeMap::new();
+    for &pair in input {
+        *counts.entry(Relation::of(pair)).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn explain_census(input: &Input) {
+    if explain::enabled() {
+        for (relation, count) in census(input) {
+            step!("{relation:?} pairs: {count}");
+        }
+    }
+}
+
+// Every elf's assignment, numbering elves from 1 in the order they appear
+fn assignments(input: &Input) -> impl Iterator<Item = (usize, (u32, u32))> + '_ {
+    input
+        .iter()
+        .flat_map(|&(left, right)| [left, right])
+        .enumerate()
+        .map(|(i, sections)| (i + 1, sections))
+}
+
+/// How many elves are assigned each section, from section 1 to the last one assigned
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    // Inclusive runs of sections with the same number of elves
+    runs: Vec<(u32, u32, usize)>,
+}
+
+impl Coverage {
+    pub fn new(input: &Input) -> Self {
+        // Changes in the number of elves at each section
+        let mut changes = BTreeMap::new();
+        for (_, (start, end)) in assignments(input) {
+            *changes.entry(start).or_insert(0) += 1;
+            *changes.entry(end + 1).or_insert(0) -= 1;
+        }
+
+        let mut runs = vec![];
+        let mut section = 1;
+        let mut elves = 0_i64;
+        for (at, change) in changes {
+            if at > section {
+                match runs.last_mut() {
+                    Some((_, end, n)) if *n == elves as usize => *end = at - 1,
+                    _ => runs.push((section, at - 1, elves as usize)),
+                }
+            }
+            section = section.max(at);
+            elves += change;
+        }
+        Coverage { runs }
+    }
+
+    /// Runs of sections no elf is assigned
+    pub fn uncovered(&self) -> Vec<(u32, u32)> {
+        self.runs
+            .iter()
+            .filter(|&&(_, _, elves)| elves == 0)
+            .map(|&(start, end, _)| (start, end))
+            .collect()
+    }
+
+    /// Runs of sections assigned to more than `elves` elves, and how many
+    pub fn more_than(&self, elves: usize) -> Vec<(u32, u32, usize)> {
+        self.runs
+            .iter()
+            .copied()
+            .filter(|&(_, _, n)| n > elves)
+            .collect()
+    }
+
+    /// Most elves assigned the same section
+    pub fn max(&self) -> usize {
+        self.runs
+            .iter()
+            .map(|&(_, _, elves)| elves)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// The fewest elves to relieve of their assignments so no section has two
+///
+/// Keeping the assignment that ends first and then every next one that does
+/// not overlap the last kept is optimal, as in interval scheduling.
+pub fn fewest_to_drop(input: &Input) -> Vec<usize> {
+    let mut by_end = assignments(input).collect::<Vec<_>>();
+    by_end.sort_by_key(|&(elf, (start, end))| (end, start, elf));
+
+    let mut dropped = vec![];
+    let mut last_end = None;
+    for (elf, (start, end)) in by_end {
+        match last_end {
+            Some(last) if start <= last => dropped.push(elf),
+            _ => last_end = Some(end),
+        }
+    }
+    dropped.sort_unstable();
+    dropped
+}
+
+fn explain_coverage(input: &Input) {
+    if !explain::enabled() {
+        return;
+    }
+
+    let coverage = Coverage::new(input);
+    let runs = |runs: Vec<(u32, u32)>| match runs.len() {
+        0 => "none".to_string(),
+        _ => runs
+            .iter()
+            .map(|(start, end)| format!("{start}-{end}"))
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+    step!(
+        "sections no elf is assigned: {}",
+        runs(coverage.uncovered())
+    );
+
+    let crowded = config::param(4, "crowded", 2);
+    let more = coverage.more_than(crowded);
+    step!(
+        "sections with more than {crowded} elves: {}",
+        runs(more.iter().map(|&(start, end, _)| (start, end)).collect())
+    );
+    step!("at most {} elves share a section", coverage.max());
+    step!(
+        "relieving {} elves leaves no section shared",
+        fewest_to_drop(input).len()
+    );
+}
+
+#[aoc(day4, part1)]
+pub fn solve_part1(input: &Input) -> usize {
+    explain_census(input);
+    input
+        .iter()
+        .filter(|&&pair| Relation::of(pair).contains())
+        .count()
+}
+
+#[aoc(day4, part2)]
+pub fn solve_part2(input: &Input) -> usize {
+    explain_coverage(input);
+    input
+        .iter()
+        .filter(|&&pair| Relation::of(pair).overlaps())
+        .count()
+}
@@ -0,0 +1,255 @@
+use crate::simulation::Simulation;
+use aoc_runner_derive::{aoc, aoc_generator};
+use regex::Regex;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+type Input = (Vec<Vec<char>>, Vec<(u32, usize, usize)>);
+
+macro_rules! regex {
+    ($re:literal $(,)?) => {{
+        static RE: once_cell::sync::OnceCell<regex::Regex> = once_cell::sync::OnceCell::new();
+        RE.get_or_init(|| regex::Regex::new($re).unwrap())
+    }};
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DrawingError {
+    MissingMoves,
+    /// The last line of the drawing should number the stacks from 1
+    Footer {
+        position: usize,
+        label: String,
+    },
+    Malformed {
+        line: usize,
+        text: String,
+    },
+    /// A crate in a column the footer has no number for
+    StackCount {
+        stacks: usize,
+        column: usize,
+    },
+    /// A crate with nothing under it
+    Floating {
+        line: usize,
+        stack: usize,
+    },
+}
+
+impl Display for DrawingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            DrawingError::MissingMoves => {
+                write!(f, "no blank line between the drawing and the moves")
+            }
+            DrawingError::Footer { position, label } => {
+                write!(f, "stack {position} is labelled {label:?} in the footer")
+            }
+            DrawingError::Malformed { line, text } => {
+                write!(f, "line {line}: malformed row {text:?}")
+            }
+            DrawingError::StackCount { stacks, column } => {
+                write!(
+                    f,
+                    "crate in column {column}, but the footer numbers {stacks} stacks"
+                )
+            }
+            DrawingError::Floating { line, stack } => {
+                write!(
+                    f,
+                    "line {line}: crate on stack {stack} has nothing under it"
+                )
+            }
+        }
+    }
+}
+
+impl Error for DrawingError {}
+
+/// The stacks in a drawing of crates like `[A] [B]`, bottom crate first
+pub fn parse_drawing(drawing: &str) -> Result<Vec<Vec<char>>, DrawingError> {
+    let mut rows = drawing.lines().collect::<Vec<_>>();
+    let footer = rows.pop().unwrap_or_default();
+
+    let labels = footer.split_whitespace().collect::<Vec<_>>();
+    if labels.is_empty() {
+        return Err(DrawingError::Footer {
+            position: 1,
+            label: footer.to_string(),
+        });
+    }
+    for (i, label) in labels.iter().enumerate() {
+        if label.parse::<usize>() != Ok(i + 1) {
+            return Err(DrawingError::Footer {
+                position: i + 1,
+                label: label.to_string(),
+            });
+        }
+    }
+
+    let mut stacks = vec![vec![]; labels.len()];
+    for (line, row) in rows.iter().enumerate().rev() {
+        let height = rows.len() - 1 - line;
+        for (column, cell) in row.as_bytes().chunks(4).enumerate() {
+            let item = match cell {
+                b"   " | b"    " => continue,
+                [b'[', item, b']'] | [b'[', item, b']', b' '] => *item as char,
+                _ => {
+                    return Err(DrawingError::Malformed {
+                        line: line + 1,
+                        text: row.to_string(),
+                    })
+                }
+            };
+
+            let stack = stacks.get_mut(column).ok_or(DrawingError::StackCount {
+                stacks: labels.len(),
+                column: column + 1,
+            })?;
+            if stack.len() != height {
+                return Err(DrawingError::Floating {
+                    line: line + 1,
+                    stack: column + 1,
+                });
+            }
+            stack.push(item);
+        }
+    }
+
+    Ok(stacks)
+}
+
+#[aoc_generator(day5)]
+pub fn day5_generator(input: &str) -> Input {
+    let (drawing, moves) = input
+        .split_once("\n\n")
+        .unwrap_or_else(|| panic!("{}", DrawingError::MissingMoves));
+    let stacks = parse_drawing(drawing).unwrap_or_else(|e| panic!("{e}"));
+
+    let expr: &Regex = regex!(r"move (\d+) from (\d+) to (\d+)");
+
+    (
+        stacks,
+        moves
+            .lines()
+            .map(|line| {
+                let captures = expr.captures(line).unwrap();
+                (
+                    captures.get(1).unwrap().as_str().parse::<u32>().unwrap(),
+                    captures.get(2).unwrap().as_str().parse::<usize>().unwrap() - 1,
+                    captures.get(3).unwrap().as_str().parse::<usize>().unwrap() - 1,
+                )
+            })
+            .collect(),
+    )
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CrateMover {
+    // Moves crates one at a time
+    Model9000,
+    // Moves a whole stack of crates at once
+    Model9001,
+}
+
+pub struct Crane<'a> {
+    stacks: Vec<Vec<char>>,
+    instructions: &'a [(u32, usize, usize)],
+    next: usize,
+    model: CrateMover,
+}
+
+impl<'a> Crane<'a> {
+    pub fn new(input: &'a Input, model: CrateMover) -> Self {
+        Crane {
+            stacks: input.0.clone(),
+            instructions: &input.1,
+            next: 0,
+            model,
+        }
+    }
+
+    pub fn top(&self) -> String {
+        self.stacks.iter().filter_map(|m| m.last()).collect()
+    }
+}
+
+pub struct Stacks<'a>(&'a [Vec<char>]);
+
+impl<'a> Display for Stacks<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let height = self.0.iter().map(|s| s.len()).max().unwrap_or(0);
+
+        for level in (0..height).rev() {
+            let row = self
+                .0
+                .iter()
+                .map(|s| match s.get(level) {
+                    Some(c) => format!("[{c}]"),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            writeln!(f, "{}", row.trim_end())?;
+        }
+
+        write!(
+            f,
+            "{}",
+            (1..=self.0.len())
+                .map(|i| format!(" {i} "))
+                .collect::<Vec<_>>()
+                .join(" ")
+        )
+    }
+}
+
+impl<'a> Simulation for Crane<'a> {
+    type Snapshot<'b>
+        = Stacks<'b>
+    where
+        Self: 'b;
+
+    fn step(&mut self) {
+        let (num, from, to) = self.instructions[self.next];
+
+        match self.model {
+            CrateMover::Model9000 => {
+                for _ in 0..num {
+                    let popped = self.stacks[from].pop().unwrap();
+                    self.stacks[to].push(popped);
+                }
+            }
+            CrateMover::Model9001 => {
+                let split = self.stacks[from].len() - num as usize;
+                let mut moved = self.stacks[from].split_off(split);
+                self.stacks[to].append(&mut moved);
+            }
+        }
+
+        self.next += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.next >= self.instructions.len()
+    }
+
+    fn snapshot(&self) -> Self::Snapshot<'_> {
+        Stacks(&self.stacks)
+    }
+}
+
+#[aoc(day5, part1)]
+pub fn solve_part1(input: &Input) -> String {
+    let mut crane = Crane::new(input, CrateMover::Model9000);
+    crane.run();
+    crane.top()
+}
+
+#[aoc(day5, part2)]
+pub fn solve_part2(input: &Input) -> String {
+    let mut crane = Crane::new(input, CrateMover::Model9001);
+    crane.run();
+    crane.top()
+}
@@ -0,0 +1,45 @@
+use aoc_runner_derive::aoc;
+
+type Input<'a> = &'a [u8];
+
+pub fn day6_generator(input: &str) -> Input<'_> {
+    input.as_bytes()
+}
+
+fn all_different(window: &[u8]) -> bool {
+    let mut seen = [false; 256];
+    window
+        .iter()
+        .all(|&b| !std::mem::replace(&mut seen[b as usize], true))
+}
+
+pub fn solve_part1(input: &Input) -> usize {
+    input
+        .windows(4)
+        .enumerate()
+        .find(|(_, v)| all_different(v))
+        .unwrap()
+        .0
+        + 4
+}
+
+pub fn solve_part2(input: &Input) -> usize {
+    input
+        .windows(14)
+        .enumerate()
+        .find(|(_, v)| all_different(v))
+        .unwrap()
+        .0
+        + 14
+}
+
+// Parses as part of solving, see `runner::day!`
+#[aoc(day6, part1)]
+fn part1(input: &str) -> usize {
+    solve_part1(&day6_generator(input))
+}
+
+#[aoc(day6, part2)]
+fn part2(input: &str) -> usize {
+    solve_part2(&day6_generator(input))
+}
@@ -0,0 +1,258 @@
+use crate::arith::{self, OverflowError};
+use crate::config;
+use crate::dot;
+use crate::explain::{self, step};
+use aoc_runner_derive::aoc;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, digit1, line_ending, not_line_ending},
+    combinator::map_res,
+    multi::separated_list1,
+    sequence::tuple,
+    IResult,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq)]
+pub enum Command<'a> {
+    Ls,
+    Cd(&'a str),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Line<'a> {
+    Command(Command<'a>),
+    File(&'a str, usize),
+    Directory(&'a str),
+}
+
+fn parse_ls(input: &str) -> IResult<&str, Command<'_>> {
+    let (input, _) = tag("ls")(input)?;
+
+    Ok((input, Command::Ls))
+}
+
+fn parse_cd(input: &str) -> IResult<&str, Command<'_>> {
+    let (input, (_, path)) = tuple((tag("cd "), not_line_ending))(input)?;
+
+    Ok((input, Command::Cd(path)))
+}
+
+fn parse_command(input: &str) -> IResult<&str, Command<'_>> {
+    alt((parse_ls, parse_cd))(input)
+}
+
+fn parse_line_command(input: &str) -> IResult<&str, Line<'_>> {
+    let (input, (_, command)) = tuple((tag("$ "), parse_command))(input)?;
+
+    Ok((input, Line::Command(command)))
+}
+
+fn parse_file(input: &str) -> IResult<&str, Line<'_>> {
+    let (input, (size, _, filename)) = tuple((
+        map_res(digit1, |s: &str| s.parse::<usize>()),
+        tag(" "),
+        not_line_ending,
+    ))(input)?;
+
+    Ok((input, Line::File(filename, size)))
+}
+
+fn parse_directory(input: &str) -> IResult<&str, Line<'_>> {
+    let (input, (_, directory)) = tuple((tag("dir "), alpha1))(input)?;
+
+    Ok((input, Line::Directory(directory)))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Line<'_>> {
+    alt((parse_line_command, parse_file, parse_directory))(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Line<'_>>> {
+    separated_list1(line_ending, parse_line)(input)
+}
+
+type Input<'a> = Vec<Line<'a>>;
+
+fn display_path(path: &[&str]) -> String {
+    format!("/{}", path.join("/"))
+}
+
+pub fn day7_generator(input: &str) -> Input<'_> {
+    parse_input(input).unwrap().1
+}
+
+type Sizes<'a> = HashMap<Vec<&'a str>, usize>;
+
+// Total size of the files directly in each directory
+fn file_sizes<'a>(input: &Input<'a>) -> Sizes<'a> {
+    let mut master_set: Sizes = HashMap::new();
+    let mut current_path = vec![];
+
+    input.iter().for_each(|line| {
+        match line {
+            Line::Command(Command::Cd(path)) => {
+                let path = *path;
+                // Navigate to new directory
+                match path {
+                    ".." => {
+                        current_path.pop();
+                    }
+                    "/" => {
+                        current_path.clear();
+                    }
+                    _ => {
+                        current_path.push(path);
+                    }
+                }
+            }
+            Line::File(_, size) => {
+                // Add file to current path
+                master_set
+                    .entry(current_path.clone())
+                    .and_modify(|total_size| *total_size += size)
+                    .or_insert(*size);
+            }
+            Line::Command(Command::Ls) | Line::Directory(_) => {} // New directory in this folder
+        }
+    });
+
+    master_set
+}
+
+// Total size of everything under each directory
+fn directory_sizes<'a>(master_set: &Sizes<'a>) -> Sizes<'a> {
+    let mut total_sizes: Sizes = HashMap::new();
+
+    for (path, size) in master_set.iter() {
+        let mut paths = path.clone();
+
+        loop {
+            total_sizes
+                .entry(paths.clone())
+                .and_modify(|total_size| *total_size += size)
+                .or_insert(*size);
+
+            if paths.pop().is_none() {
+                break;
+            }
+        }
+    }
+
+    total_sizes
+}
+
+// The smallest directory that frees enough space for the update, and its size
+fn directory_to_delete<'a, 'b>(
+    total_sizes: &'b Sizes<'a>,
+    used_space: usize,
+) -> Result<(&'b Vec<&'a str>, usize), OverflowError> {
+    let disk_size = config::param(7, "disk_size", 70_000_000);
+    let space_needed: usize = config::param(7, "space_needed", 30_000_000);
+    let unused_space = arith::sub(disk_size, used_space).map_err(|e| e.during(7, 2))?;
+    // Nothing more to free is fine when the disk already has room
+    let needed_space = space_needed.saturating_sub(unused_space);
+    step!("{used_space} of {disk_size} used, {needed_space} more must be freed");
+
+    let (path, size) = total_sizes
+        .iter()
+        .filter(|&(_, &s)| s >= needed_space)
+        .min_by_key(|&(_, &s)| s - needed_space)
+        .unwrap();
+    Ok((path, *size))
+}
+
+pub fn solve_part1(input: &Input) -> usize {
+    let total_sizes = directory_sizes(&file_sizes(input));
+
+    let small_dir = config::param(7, "small_dir", 100_000);
+    if explain::enabled() {
+        let mut small = total_sizes
+            .iter()
+            .filter(|&(_, &v)| v < small_dir)
+            .collect::<Vec<_>>();
+        small.sort();
+        for (path, size) in small {
+            step!("{} holds {size}, under {small_dir}", display_path(path));
+        }
+    }
+    total_sizes.values().filter(|&&v| v < small_dir).sum()
+}
+
+pub fn solve_part2(input: &Input) -> Result<usize, OverflowError> {
+    let master_set = file_sizes(input);
+    let total_sizes = directory_sizes(&master_set);
+    let used_space = master_set.values().sum();
+
+    let (path, size) = directory_to_delete(&total_sizes, used_space)?;
+    step!(
+        "{} is the smallest directory that frees enough, at {size}",
+        display_path(path)
+    );
+    Ok(size)
+}
+
+/// The directory tree with every directory's total size, and the one to delete highlighted
+pub fn directory_graph(input: &Input) -> dot::Graph {
+    let master_set = file_sizes(input);
+    let total_sizes = directory_sizes(&master_set);
+    let deleted = directory_to_delete(&total_sizes, master_set.values().sum())
+        .ok()
+        .map(|(path, _)| path.clone());
+
+    let mut graph = dot::Graph::new("day 7", true);
+    graph.set("rankdir", "LR");
+
+    let mut directories = total_sizes.iter().collect::<Vec<_>>();
+    directories.sort();
+    for (path, size) in directories {
+        let name = path.last().copied().unwrap_or("/");
+        let label = format!("{name}\n{size}");
+        let mut attrs = vec![("label", label.as_str()), ("shape", "folder")];
+        let highlighted = deleted.as_ref() == Some(path);
+        if highlighted {
+            attrs.extend(dot::HIGHLIGHT);
+        }
+        graph.node(&display_path(path), &attrs);
+
+        if let Some((_, parent)) = path.split_last() {
+            let edge_attrs = if highlighted { dot::HIGHLIGHT } else { &[] };
+            graph.edge(&display_path(parent), &display_path(path), edge_attrs);
+        }
+    }
+
+    let mut current_path = vec![];
+    for line in input {
+        match line {
+            Line::Command(Command::Cd("..")) => {
+                current_path.pop();
+            }
+            Line::Command(Command::Cd("/")) => current_path.clear(),
+            Line::Command(Command::Cd(path)) => current_path.push(*path),
+            Line::File(name, size) => {
+                let directory = display_path(&current_path);
+                let id = format!("{}/{name}", directory.trim_end_matches('/'));
+                graph.node(
+                    &id,
+                    &[("label", &format!("{name}\n{size}")), ("shape", "note")],
+                );
+                graph.edge(&directory, &id, &[]);
+            }
+            Line::Command(Command::Ls) | Line::Directory(_) => {}
+        }
+    }
+
+    graph
+}
+
+// Parses as part of solving, see `runner::day!`
+#[aoc(day7, part1)]
+fn part1(input: &str) -> usize {
+    solve_part1(&day7_generator(input))
+}
+
+#[aoc(day7, part2)]
+fn part2(input: &str) -> Result<usize, OverflowError> {
+    solve_part2(&day7_generator(input))
+}
@@ -11,6 +11,25 @@ pub fn day8_generator(input: &str) -> Input {
         .collect()
 }
 
+/// Whether each tree can be seen from outside the grid
+pub fn visibility(input: &Input) -> Vec<Vec<bool>> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, &tree)| {
+                    (0..i).all(|k| input[k][j] < tree)
+                        || (i + 1..input.len()).all(|k| input[k][j] < tree)
+                        || (0..j).all(|k| row[k] < tree)
+                        || (j + 1..row.len()).all(|k| row[k] < tree)
+                })
+                .collect()
+        })
+        .collect()
+}
+
 #[aoc(day8, part1)]
 pub fn solve_part1(input: &Input) -> usize {
     let mut counted = HashSet::new();
@@ -110,9 +129,7 @@ pub fn solve_part2(input: &Input) -> usize {
 
             let mut dist = 0;
 
-            for k in (j + 1)..input[i].len() {
-                let check = input[i][k];
-
+            for &check in &input[i][(j + 1)..] {
                 dist += 1;
                 if check >= *curr {
                     break;
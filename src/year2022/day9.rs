@@ -1,5 +1,7 @@
+use crate::simulation::Simulation;
 use aoc_runner_derive::{aoc, aoc_generator};
 use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
 
 use nom::{
     branch::alt,
@@ -18,6 +20,14 @@ pub enum Direction {
     Right(u8),
 }
 
+impl Direction {
+    fn distance(&self) -> u8 {
+        match self {
+            Direction::Up(n) | Direction::Down(n) | Direction::Left(n) | Direction::Right(n) => *n,
+        }
+    }
+}
+
 fn parse_direction(input: &str) -> IResult<&str, Direction> {
     let (input, (direction, size)) = separated_pair(
         alt((
@@ -103,46 +113,9 @@ pub fn day9_generator(input: &str) -> Input {
 
 #[aoc(day9, part1)]
 pub fn solve_part1(input: &Input) -> usize {
-    let mut visited = HashSet::new();
-
-    let mut head: (i16, i16) = (0, 0);
-    let mut tail: (i16, i16) = (0, 0);
-
-    visited.insert(tail);
-
-    for direction in input {
-        match direction {
-            Direction::Up(n) => {
-                for _ in 0..*n {
-                    head.0 += 1;
-                    tail = update_direction(head, tail);
-                    visited.insert(tail);
-                }
-            }
-            Direction::Down(n) => {
-                for _ in 0..*n {
-                    head.0 -= 1;
-                    tail = update_direction(head, tail);
-                    visited.insert(tail);
-                }
-            }
-            Direction::Left(n) => {
-                for _ in 0..*n {
-                    head.1 -= 1;
-                    tail = update_direction(head, tail);
-                    visited.insert(tail);
-                }
-            }
-            Direction::Right(n) => {
-                for _ in 0..*n {
-                    head.1 += 1;
-                    tail = update_direction(head, tail);
-                    visited.insert(tail);
-                }
-            }
-        }
-    }
-    visited.len()
+    let mut rope = Rope::new(input, 2);
+    rope.run();
+    rope.visited()
 }
 
 fn update_rope(rope: Vec<(i16, i16)>) -> Vec<(i16, i16)> {
@@ -156,48 +129,85 @@ fn update_rope(rope: Vec<(i16, i16)>) -> Vec<(i16, i16)> {
         .collect()
 }
 
-#[aoc(day9, part2)]
-pub fn solve_part2(input: &Input) -> usize {
-    let mut rope = vec![(0, 0); 10];
+pub struct Rope<'a> {
+    knots: Vec<(i16, i16)>,
+    directions: &'a [Direction],
+    next: usize,
+    remaining: u8,
+    visited: HashSet<(i16, i16)>,
+}
 
-    let mut visited = HashSet::new();
-    visited.insert((0, 0));
+impl<'a> Rope<'a> {
+    pub fn new(input: &'a Input, knots: usize) -> Self {
+        Rope {
+            knots: vec![(0, 0); knots],
+            directions: input,
+            next: 0,
+            remaining: input.first().map(Direction::distance).unwrap_or(0),
+            visited: [(0, 0)].into_iter().collect(),
+        }
+    }
 
-    for direction in input {
-        match direction {
-            Direction::Up(n) => {
-                for _ in 0..*n {
-                    rope[0].1 += 1;
-
-                    rope = update_rope(rope);
-                    visited.insert(*rope.last().unwrap());
-                }
-            }
-            Direction::Down(n) => {
-                for _ in 0..*n {
-                    rope[0].1 -= 1;
-
-                    rope = update_rope(rope);
-                    visited.insert(*rope.last().unwrap());
-                }
-            }
-            Direction::Left(n) => {
-                for _ in 0..*n {
-                    rope[0].0 -= 1;
-
-                    rope = update_rope(rope);
-                    visited.insert(*rope.last().unwrap());
-                }
-            }
-            Direction::Right(n) => {
-                for _ in 0..*n {
-                    rope[0].0 += 1;
-
-                    rope = update_rope(rope);
-                    visited.insert(*rope.last().unwrap());
-                }
-            }
+    pub fn visited(&self) -> usize {
+        self.visited.len()
+    }
+}
+
+pub struct Knots<'a>(&'a [(i16, i16)], usize);
+
+impl<'a> Display for Knots<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        for (i, knot) in self.0.iter().enumerate() {
+            let name = if i == 0 {
+                "H".to_string()
+            } else {
+                i.to_string()
+            };
+            writeln!(f, "{name:>2}: ({}, {})", knot.0, knot.1)?;
         }
+        write!(f, "tail visited: {}", self.1)
+    }
+}
+
+impl<'a> Simulation for Rope<'a> {
+    type Snapshot<'b>
+        = Knots<'b>
+    where
+        Self: 'b;
+
+    fn step(&mut self) {
+        while self.remaining == 0 {
+            self.next += 1;
+            self.remaining = self.directions[self.next].distance();
+        }
+
+        match self.directions[self.next] {
+            Direction::Up(_) => self.knots[0].1 += 1,
+            Direction::Down(_) => self.knots[0].1 -= 1,
+            Direction::Left(_) => self.knots[0].0 -= 1,
+            Direction::Right(_) => self.knots[0].0 += 1,
+        }
+        self.remaining -= 1;
+
+        self.knots = update_rope(std::mem::take(&mut self.knots));
+        self.visited.insert(*self.knots.last().unwrap());
     }
-    visited.len()
+
+    fn is_done(&self) -> bool {
+        self.remaining == 0
+            && self.directions[(self.next + 1).min(self.directions.len())..]
+                .iter()
+                .all(|d| d.distance() == 0)
+    }
+
+    fn snapshot(&self) -> Self::Snapshot<'_> {
+        Knots(&self.knots, self.visited())
+    }
+}
+
+#[aoc(day9, part2)]
+pub fn solve_part2(input: &Input) -> usize {
+    let mut rope = Rope::new(input, 10);
+    rope.run();
+    rope.visited()
 }
@@ -0,0 +1,39 @@
+use crate::runner::{day, Day};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day2;
+pub mod day20;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub const DAYS: &[Day] = &[
+    day!(1, day1, day1_generator),
+    day!(2, day2, day2_generator),
+    day!(3, day3, day3_generator),
+    day!(4, day4, day4_generator),
+    day!(5, day5, day5_generator),
+    day!(6, day6, day6_generator),
+    day!(7, day7, day7_generator),
+    day!(8, day8, day8_generator),
+    day!(9, day9, day9_generator),
+    day!(10, day10, day10_generator),
+    day!(11, day11, day11_generator),
+    day!(12, day12, day12_generator),
+    day!(13, day13, day13_generator),
+    day!(14, day14, day14_generator),
+    day!(15, day15, day15_generator),
+    day!(16, day16, day16_generator),
+    day!(20, day20, day20_generator),
+];
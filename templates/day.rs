@@ -0,0 +1,43 @@
+use crate::parse;
+use aoc_runner_derive::{aoc, aoc_generator};
+use nom::{character::complete, IResult};
+
+type Input = Vec<u32>;
+
+fn parse_line(input: &str) -> IResult<&str, u32> {
+    complete::u32(input)
+}
+
+#[aoc_generator(day{{day}})]
+pub fn day{{day}}_generator(input: &str) -> Input {
+    parse::all(parse::lines(parse_line), input)
+}
+
+#[aoc(day{{day}}, part1)]
+pub fn solve_part1(_input: &Input) -> usize {
+    todo!("day {{day}} part 1")
+}
+
+#[aoc(day{{day}}, part2)]
+pub fn solve_part2(_input: &Input) -> usize {
+    todo!("day {{day}} part 2")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part1_example() {
+        assert_eq!(solve_part1(&day{{day}}_generator(EXAMPLE)), 0);
+    }
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn part2_example() {
+        assert_eq!(solve_part2(&day{{day}}_generator(EXAMPLE)), 0);
+    }
+}